@@ -0,0 +1,512 @@
+// RepoCard Studio - Command-Line Interface
+// LAZYFROG (KZ) — kindware.dev
+
+use clap::{Args, Parser, Subcommand};
+use repocard_core::{
+    export_full_share_kit, generate_press_kit, generate_readme_snippet, generate_release_notes_draft, generate_card_svg,
+    load_fonts_dir, outline_text, rasterize_svg_as, AppError, CommitInfo, CommitRange, ExportOptions, ImageFormat,
+    ProjectConfig, RasterOptions, RepoMetadata, RepoProvider, SizePreset,
+};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::net::SocketAddr;
+use std::process::ExitCode;
+
+mod serve;
+
+/// Commits the release notes and share kit draw on when no range is given, as in the app
+const DEFAULT_COMMIT_COUNT: u32 = 20;
+
+/// Generate repository cards and share kits without the desktop app
+#[derive(Debug, Parser)]
+#[command(name = "repocard", version)]
+struct Cli {
+    /// Also load the font files in this directory, e.g. to render with the same fonts everywhere
+    #[arg(long, global = true)]
+    fonts_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Write the SVG card
+    Card {
+        #[command(flatten)]
+        repo: RepoArgs,
+        #[command(flatten)]
+        card: CardArgs,
+        /// File to write; standard output when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write the card as a PNG, JPEG, WebP or AVIF image
+    #[command(visible_alias = "image")]
+    Png {
+        #[command(flatten)]
+        repo: RepoArgs,
+        #[command(flatten)]
+        card: CardArgs,
+        #[command(flatten)]
+        image: ImageArgs,
+        /// Width in pixels, the size preset's when omitted; the height follows the card's aspect ratio
+        #[arg(long)]
+        width: Option<u32>,
+        /// File to write; its extension picks the format when `--format` is not given.
+        /// `repo-card.<format>` when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write the README snippet
+    Readme {
+        #[command(flatten)]
+        repo: RepoArgs,
+        #[arg(long)]
+        no_attribution: bool,
+        /// File to write; standard output when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write a release notes draft
+    ReleaseNotes {
+        #[command(flatten)]
+        repo: RepoArgs,
+        #[command(flatten)]
+        commits: CommitArgs,
+        /// Version heading; the end of `--range` when it is a tag
+        #[arg(long)]
+        version: Option<String>,
+        #[arg(long)]
+        no_attribution: bool,
+        /// File to write; standard output when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write the press kit overview
+    PressKit {
+        #[command(flatten)]
+        repo: RepoArgs,
+        #[arg(long)]
+        no_attribution: bool,
+        /// File to write; standard output when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write the full share kit folder
+    Export {
+        #[command(flatten)]
+        repo: RepoArgs,
+        #[command(flatten)]
+        card: CardArgs,
+        #[command(flatten)]
+        image: ImageArgs,
+        #[command(flatten)]
+        commits: CommitArgs,
+        /// Also write the card in every size preset
+        #[arg(long)]
+        all_sizes: bool,
+        /// Directory the `share-kit` folder is created in
+        #[arg(short = 'o', long, default_value = ".")]
+        output_dir: PathBuf,
+    },
+    /// Serve cards over HTTP at `/<owner>/<repo>.png` or `.svg`, e.g. as an og:image backend
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8787")]
+        bind: SocketAddr,
+        /// Access token; `GITHUB_TOKEN`/`GH_TOKEN`, `GITLAB_TOKEN` or `GITEA_TOKEN` when omitted
+        #[arg(long)]
+        token: Option<String>,
+        /// Seconds clients and CDNs may reuse an image before asking again
+        #[arg(long, default_value_t = 3600)]
+        max_age: u64,
+    },
+}
+
+/// Which repository to read and how
+#[derive(Debug, Args)]
+struct RepoArgs {
+    /// Repository URL, `owner/repo` or local checkout path
+    repo: String,
+    /// Access token; `GITHUB_TOKEN`/`GH_TOKEN`, `GITLAB_TOKEN` or `GITEA_TOKEN` when omitted
+    #[arg(long)]
+    token: Option<String>,
+    /// Revalidate cached API responses instead of trusting their TTL
+    #[arg(long)]
+    refresh: bool,
+}
+
+/// Card look, mirroring `ExportOptions`. Flags override the repository's `.repocard.toml`.
+#[derive(Debug, Args)]
+struct CardArgs {
+    /// Card template: modern (default), minimal, gradient or a user template id
+    #[arg(short, long)]
+    template: Option<String>,
+    /// Leave the "Made with RepoCard Studio" line off
+    #[arg(long)]
+    no_attribution: bool,
+    #[arg(long)]
+    primary_color: Option<String>,
+    #[arg(long)]
+    secondary_color: Option<String>,
+    /// Add a row of contributor avatars
+    #[arg(long)]
+    contributors: bool,
+    /// Add a stacked language bar with a legend
+    #[arg(long)]
+    languages: bool,
+    /// Convert text to paths in the SVG, so it looks the same without the fonts
+    #[arg(long)]
+    outline_text: bool,
+    /// Platform size: og (default), twitter, linkedin, square, story or github
+    #[arg(long, value_parser = parse_size)]
+    size: Option<SizePreset>,
+}
+
+fn parse_size(id: &str) -> Result<SizePreset, String> {
+    SizePreset::parse(id).map_err(|e| e.message().to_string())
+}
+
+/// How the raster card is encoded. Flags override the repository's `.repocard.toml`.
+#[derive(Debug, Args)]
+struct ImageArgs {
+    /// png, jpeg, webp or avif
+    #[arg(long, value_parser = parse_image_format)]
+    format: Option<ImageFormat>,
+    /// 1–100 for JPEG, WebP and AVIF; WebP is lossless without it
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=100))]
+    quality: Option<u8>,
+    /// Lower the quality until the file is at most this many kilobytes
+    #[arg(long)]
+    max_kb: Option<u32>,
+}
+
+fn parse_image_format(name: &str) -> Result<ImageFormat, String> {
+    ImageFormat::parse(name).map_err(|e| e.message().to_string())
+}
+
+impl ImageArgs {
+    /// The configured image options with these flags on top; `output` names the format when `--format` does not
+    fn apply(&self, image: &mut RasterOptions, output: Option<&Path>) {
+        let from_extension = output
+            .and_then(|path| path.extension())
+            .and_then(|extension| ImageFormat::parse(&extension.to_string_lossy()).ok());
+        if let Some(format) = self.format.or(from_extension) {
+            image.format = format;
+        }
+        if self.quality.is_some() {
+            image.quality = self.quality;
+        }
+        if self.max_kb.is_some() {
+            image.max_kb = self.max_kb;
+        }
+    }
+}
+
+/// Which commits release notes are drafted from
+#[derive(Debug, Args)]
+struct CommitArgs {
+    /// `base..head` between tags or SHAs, or a single branch
+    #[arg(long)]
+    range: Option<String>,
+    /// Only commits on or after this date (RFC 3339 or `YYYY-MM-DD`)
+    #[arg(long)]
+    since: Option<String>,
+    /// Only commits on or before this date (RFC 3339 or `YYYY-MM-DD`)
+    #[arg(long)]
+    until: Option<String>,
+    /// Most commits to include; every commit in `--range` when omitted, else 20
+    #[arg(long)]
+    limit: Option<u32>,
+}
+
+impl CardArgs {
+    fn svg(&self, metadata: &RepoMetadata) -> Result<String, AppError> {
+        let options = self.export_options(metadata.project_config.as_ref(), Path::new("."));
+        let svg = generate_card_svg(metadata, &options)?;
+        if options.outline_text {
+            outline_text(&svg)
+        } else {
+            Ok(svg)
+        }
+    }
+
+    /// The built-in defaults, then the repository's config, then these flags
+    fn export_options(&self, config: Option<&ProjectConfig>, output_dir: &Path) -> ExportOptions {
+        let mut options = project_options(config, !self.no_attribution);
+        options.output_dir = output_dir.to_string_lossy().to_string();
+        if let Some(template) = &self.template {
+            options.template_id = template.clone();
+        }
+        if self.primary_color.is_some() {
+            options.primary_color = self.primary_color.clone();
+        }
+        if self.secondary_color.is_some() {
+            options.secondary_color = self.secondary_color.clone();
+        }
+        options.show_contributors |= self.contributors;
+        options.show_languages |= self.languages;
+        options.outline_text |= self.outline_text;
+        if let Some(size) = self.size {
+            options.size = size;
+        }
+        options
+    }
+}
+
+/// Export options from the repository's `.repocard.toml`; `attribution: false` always wins
+fn project_options(config: Option<&ProjectConfig>, attribution: bool) -> ExportOptions {
+    let mut options = ExportOptions::default();
+    if let Some(config) = config {
+        config.apply_to_options(&mut options);
+    }
+    options.include_attribution &= attribution;
+    options
+}
+
+impl CommitArgs {
+    /// The requested range, or the latest commits when none is given
+    fn range(&self) -> Result<CommitRange, AppError> {
+        let mut range = match &self.range {
+            Some(spec) => CommitRange::parse(spec)?,
+            None if self.since.is_none() && self.until.is_none() => {
+                CommitRange::latest(self.limit.unwrap_or(DEFAULT_COMMIT_COUNT))
+            }
+            None => CommitRange::default(),
+        };
+        range.since = self.since.clone();
+        range.until = self.until.clone();
+        range.limit = self.limit.or(range.limit);
+        Ok(range)
+    }
+}
+
+/// Process exit status for a failure, so pipelines can tell them apart
+fn exit_code(error: &AppError) -> u8 {
+    match error {
+        AppError::Io { .. } | AppError::Render { .. } => 1,
+        AppError::Parse { .. } | AppError::Template { .. } => 2,
+        AppError::NotFound { .. } => 3,
+        AppError::Unauthorized { .. } => 4,
+        AppError::RateLimited { .. } => 5,
+        AppError::Network { .. } => 6,
+    }
+}
+
+struct Session {
+    providers: RepoProvider,
+    token: Option<String>,
+    repo: String,
+}
+
+impl Session {
+    fn new(args: &RepoArgs) -> Result<Self, AppError> {
+        let providers = RepoProvider::from_env()?;
+        Ok(Self {
+            providers: if args.refresh { providers.refreshing() } else { providers },
+            token: args.token.clone(),
+            repo: args.repo.clone(),
+        })
+    }
+
+    async fn metadata(&self) -> Result<RepoMetadata, AppError> {
+        self.providers.fetch_repository_metadata(&self.repo, self.token.clone()).await
+    }
+
+    async fn commits(&self, range: &CommitRange) -> Result<Vec<CommitInfo>, AppError> {
+        self.providers.fetch_commits(&self.repo, range, self.token.clone()).await
+    }
+}
+
+/// Write to `path`, or to standard output when there is none
+fn write_output(path: Option<&Path>, contents: &[u8]) -> Result<(), AppError> {
+    match path {
+        Some(path) => fs::write(path, contents)
+            .map_err(|e| AppError::io(format!("Failed to write {}: {}", path.display(), e))),
+        None => std::io::stdout()
+            .write_all(contents)
+            .map_err(|e| AppError::io(format!("Failed to write output: {}", e))),
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), AppError> {
+    if let Some(dir) = &cli.fonts_dir {
+        if !dir.is_dir() {
+            return Err(AppError::not_found(format!("Fonts directory not found: {}", dir.display())));
+        }
+        load_fonts_dir(dir);
+    }
+    match cli.command {
+        Command::Card { repo, card, output } => {
+            let metadata = Session::new(&repo)?.metadata().await?;
+            write_output(output.as_deref(), card.svg(&metadata)?.as_bytes())
+        }
+        Command::Png { repo, card, image, width, output } => {
+            let metadata = Session::new(&repo)?.metadata().await?;
+            let mut options = card.export_options(metadata.project_config.as_ref(), Path::new("."));
+            image.apply(&mut options.image, output.as_deref());
+            let output = output.unwrap_or_else(|| PathBuf::from(format!("repo-card.{}", options.image.format.extension())));
+            let width = width.unwrap_or(options.size.dimensions().0);
+            let encoded = rasterize_svg_as(&card.svg(&metadata)?, width, &options.image)?;
+            write_output(Some(&output), &encoded)
+        }
+        Command::Readme { repo, no_attribution, output } => {
+            let metadata = Session::new(&repo)?.metadata().await?;
+            let options = project_options(metadata.project_config.as_ref(), !no_attribution);
+            write_output(output.as_deref(), generate_readme_snippet(&metadata, options.include_attribution).as_bytes())
+        }
+        Command::ReleaseNotes { repo, commits, version, no_attribution, output } => {
+            let range = commits.range()?;
+            let session = Session::new(&repo)?;
+            let metadata = session.metadata().await?;
+            let commits = session.commits(&range).await?;
+            let options = project_options(metadata.project_config.as_ref(), !no_attribution);
+            let notes = generate_release_notes_draft(
+                &metadata,
+                &commits,
+                version,
+                Some(&range),
+                &options.release_note_categories,
+                options.include_attribution,
+            );
+            write_output(output.as_deref(), notes.as_bytes())
+        }
+        Command::PressKit { repo, no_attribution, output } => {
+            let metadata = Session::new(&repo)?.metadata().await?;
+            let options = project_options(metadata.project_config.as_ref(), !no_attribution);
+            write_output(output.as_deref(), generate_press_kit(&metadata, &options).as_bytes())
+        }
+        Command::Export { repo, card, image, commits, all_sizes, output_dir } => {
+            let range = commits.range()?;
+            let session = Session::new(&repo)?;
+            let metadata = session.metadata().await?;
+            let commits = session.commits(&range).await?;
+            let mut options = card.export_options(metadata.project_config.as_ref(), &output_dir);
+            image.apply(&mut options.image, None);
+            options.all_sizes |= all_sizes;
+            let result = export_full_share_kit(&metadata, &commits, &options).await?;
+            for file in &result.files {
+                println!("{}", Path::new(&result.output_path).join(file).display());
+            }
+            Ok(())
+        }
+        Command::Serve { bind, token, max_age } => serve::serve(bind, token, max_age).await,
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::from(exit_code(&e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_card_flags_mirror_export_options() {
+        let cli = Cli::try_parse_from([
+            "repocard", "export", "rust-lang/rust", "-t", "gradient", "--no-attribution", "--languages", "--outline-text", "--size", "story", "-o", "out",
+        ])
+        .unwrap();
+        let Command::Export { repo, card, output_dir, .. } = cli.command else {
+            panic!("expected export");
+        };
+        assert_eq!(repo.repo, "rust-lang/rust");
+        
+        let options = card.export_options(None, &output_dir);
+        assert_eq!(options.template_id, "gradient");
+        assert_eq!(options.output_dir, "out");
+        assert!(!options.include_attribution);
+        assert!(options.show_languages);
+        assert!(options.outline_text);
+        assert_eq!(options.size, SizePreset::Story);
+        assert!(!options.show_contributors);
+        assert!(Cli::try_parse_from(["repocard", "card", "owner/repo", "--size", "banner"]).is_err());
+    }
+
+    #[test]
+    fn test_flags_override_project_config() {
+        let config = ProjectConfig::parse("template = \"minimal\"\ncontributors = true\n[colors]\nprimary = \"#111111\"\n").unwrap();
+        let cli = Cli::try_parse_from(["repocard", "card", "owner/repo", "--primary-color", "#222222"]).unwrap();
+        let Command::Card { card, .. } = cli.command else {
+            panic!("expected card");
+        };
+        
+        let options = card.export_options(Some(&config), Path::new("."));
+        assert_eq!(options.template_id, "minimal");
+        assert!(options.show_contributors);
+        assert!(options.include_attribution);
+        assert_eq!(options.primary_color.as_deref(), Some("#222222"));
+        
+        let config = ProjectConfig::parse("attribution = false").unwrap();
+        assert!(!project_options(Some(&config), true).include_attribution);
+        assert!(!project_options(None, false).include_attribution);
+        assert_eq!(project_options(None, true).template_id, "modern");
+    }
+
+    #[test]
+    fn test_image_flags_pick_the_format() {
+        let parse = |args: &[&str]| {
+            let cli = Cli::try_parse_from(["repocard", "png", "owner/repo"].iter().chain(args)).unwrap();
+            let Command::Png { image, output, .. } = cli.command else {
+                panic!("expected png");
+            };
+            let mut options = RasterOptions { max_kb: Some(500), ..RasterOptions::default() };
+            image.apply(&mut options, output.as_deref());
+            options
+        };
+        assert_eq!(parse(&[]), RasterOptions { max_kb: Some(500), ..RasterOptions::default() });
+        assert_eq!(parse(&["-o", "card.WEBP"]).format, ImageFormat::Webp);
+        assert_eq!(parse(&["-o", "card.webp", "--format", "jpg"]).format, ImageFormat::Jpeg);
+        
+        let options = parse(&["--format", "avif", "--quality", "60", "--max-kb", "300"]);
+        assert_eq!(options, RasterOptions { format: ImageFormat::Avif, quality: Some(60), max_kb: Some(300) });
+        assert!(Cli::try_parse_from(["repocard", "image", "owner/repo", "--format", "gif"]).is_err());
+        assert!(Cli::try_parse_from(["repocard", "png", "owner/repo", "--quality", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_commit_range_flags() {
+        let args = |range: Option<&str>, since: Option<&str>, limit: Option<u32>| CommitArgs {
+            range: range.map(str::to_string),
+            since: since.map(str::to_string),
+            until: None,
+            limit,
+        };
+        assert_eq!(args(None, None, None).range().unwrap(), CommitRange::latest(20));
+        
+        let range = args(Some("v1.2.0..v1.3.0"), None, None).range().unwrap();
+        assert_eq!(range.base.as_deref(), Some("v1.2.0"));
+        assert_eq!(range.head.as_deref(), Some("v1.3.0"));
+        assert_eq!(range.limit, None);
+        
+        let range = args(None, Some("2026-01-01"), Some(50)).range().unwrap();
+        assert_eq!(range.since.as_deref(), Some("2026-01-01"));
+        assert_eq!(range.limit, Some(50));
+        assert!(args(Some(".."), None, None).range().is_err());
+    }
+
+    #[test]
+    fn test_exit_codes_by_kind() {
+        assert_eq!(exit_code(&AppError::template("Unknown template: retro")), 2);
+        assert_eq!(exit_code(&AppError::not_found("Repository not found")), 3);
+        assert_eq!(exit_code(&AppError::unauthorized("Bad credentials")), 4);
+        assert_eq!(exit_code(&AppError::rate_limited("Slow down", None)), 5);
+        assert_eq!(exit_code(&AppError::network("Offline")), 6);
+    }
+}
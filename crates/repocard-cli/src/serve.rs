@@ -0,0 +1,527 @@
+// RepoCard Studio - Image Server Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::project_options;
+use axum::extract::{Path, Query, State};
+use axum::http::header::{ACCEPT, CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER, VARY};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use repocard_core::{
+    generate_card_svg, is_local_path, rasterize_svg_as, AppError, ExportOptions, ImageFormat as RasterFormat, RepoMetadata,
+    RepoProvider, SizePreset,
+};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+/// Rendered images kept in memory; the oldest are dropped first
+const MAX_CACHED_IMAGES: usize = 256;
+const MIN_PNG_WIDTH: u32 = 200;
+const MAX_PNG_WIDTH: u32 = 2400;
+/// Bounds on `quality` and `max_kb`, so a query cannot ask for a long search of tiny encodes
+const MIN_QUALITY: u8 = 10;
+const MAX_QUALITY: u8 = 100;
+const MIN_MAX_KB: u32 = 50;
+const MAX_MAX_KB: u32 = 5000;
+/// `Retry-After` seconds when the forge did not say when its rate limit resets
+const DEFAULT_RETRY_AFTER: i64 = 60;
+
+/// Image formats the server renders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ImageFormat {
+    Png,
+    Svg,
+    Jpeg,
+    Webp,
+    Avif,
+}
+
+impl ImageFormat {
+    /// In order of preference when an `Accept` header rates several the same
+    const ALL: [ImageFormat; 5] = [Self::Png, Self::Svg, Self::Webp, Self::Avif, Self::Jpeg];
+
+    fn content_type(self) -> &'static str {
+        match self.raster() {
+            Some(format) => format.mime_type(),
+            None => "image/svg+xml",
+        }
+    }
+
+    fn raster(self) -> Option<RasterFormat> {
+        match self {
+            Self::Png => Some(RasterFormat::Png),
+            Self::Svg => None,
+            Self::Jpeg => Some(RasterFormat::Jpeg),
+            Self::Webp => Some(RasterFormat::Webp),
+            Self::Avif => Some(RasterFormat::Avif),
+        }
+    }
+
+    fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "svg" => Some(Self::Svg),
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpeg),
+            "webp" => Some(Self::Webp),
+            "avif" => Some(Self::Avif),
+            _ => None,
+        }
+    }
+
+    /// The format an `Accept` header prefers, PNG when it does not say
+    fn negotiate(accept: Option<&str>) -> Self {
+        let quality = |wanted: &str| {
+            accept
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(|item| {
+                    let mut parts = item.split(';').map(str::trim);
+                    let media = parts.next()?;
+                    let q = parts
+                        .find_map(|p| p.strip_prefix("q="))
+                        .and_then(|q| q.parse::<f32>().ok())
+                        .unwrap_or(1.0);
+                    (media == wanted).then_some(q)
+                })
+                .fold(0.0_f32, f32::max)
+        };
+        Self::ALL
+            .into_iter()
+            .fold((Self::Png, 0.0), |best, format| {
+                let q = quality(format.content_type());
+                if q > best.1 { (format, q) } else { best }
+            })
+            .0
+    }
+}
+
+/// Card settings a request may override; the rest come from the repository's `.repocard.toml`
+#[derive(Debug, Default, Deserialize)]
+struct CardQuery {
+    template: Option<String>,
+    attribution: Option<bool>,
+    contributors: Option<bool>,
+    languages: Option<bool>,
+    primary: Option<String>,
+    secondary: Option<String>,
+    /// Platform size preset, e.g. `twitter` or `story`
+    size: Option<SizePreset>,
+    /// Raster width in pixels; the size preset's when unset
+    width: Option<u32>,
+    /// JPEG, WebP and AVIF quality, 10–100
+    quality: Option<u8>,
+    /// Largest raster image in kilobytes, 50–5000
+    max_kb: Option<u32>,
+}
+
+impl CardQuery {
+    fn export_options(&self, metadata: &RepoMetadata, format: ImageFormat) -> ExportOptions {
+        let mut options = project_options(metadata.project_config.as_ref(), self.attribution.unwrap_or(true));
+        if let Some(template) = &self.template {
+            options.template_id = template.clone();
+        }
+        if let Some(contributors) = self.contributors {
+            options.show_contributors = contributors;
+        }
+        if let Some(languages) = self.languages {
+            options.show_languages = languages;
+        }
+        if self.primary.is_some() {
+            options.primary_color = self.primary.clone();
+        }
+        if self.secondary.is_some() {
+            options.secondary_color = self.secondary.clone();
+        }
+        if let Some(size) = self.size {
+            options.size = size;
+        }
+        if let Some(raster) = format.raster() {
+            options.image.format = raster;
+        }
+        if self.quality.is_some() {
+            options.image.quality = self.quality;
+        }
+        if self.max_kb.is_some() {
+            options.image.max_kb = self.max_kb;
+        }
+        // The repository's config is as untrusted as the query
+        options.image.quality = options.image.quality.map(|q| q.clamp(MIN_QUALITY, MAX_QUALITY));
+        options.image.max_kb = options.image.max_kb.map(|kb| kb.clamp(MIN_MAX_KB, MAX_MAX_KB));
+        options
+    }
+
+    fn png_width(&self, options: &ExportOptions) -> u32 {
+        self.width.unwrap_or(options.size.dimensions().0).clamp(MIN_PNG_WIDTH, MAX_PNG_WIDTH)
+    }
+}
+
+/// Rendered images by render key, evicting the oldest beyond `capacity`
+#[derive(Debug)]
+struct ImageCache {
+    capacity: usize,
+    images: HashMap<String, Arc<Vec<u8>>>,
+    order: VecDeque<String>,
+}
+
+impl ImageCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            images: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<Arc<Vec<u8>>> {
+        self.images.get(key).cloned()
+    }
+
+    fn insert(&mut self, key: String, image: Arc<Vec<u8>>) {
+        if self.images.insert(key.clone(), image).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.images.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ServerState {
+    providers: RepoProvider,
+    token: Option<String>,
+    /// `Cache-Control` max-age for rendered images, in seconds
+    max_age: u64,
+    images: Arc<Mutex<ImageCache>>,
+}
+
+/// Serve cards at `GET /<owner>/<repo>.png` (or `.jpg`, `.webp`, `.avif`, `.svg`) until the process is stopped
+pub async fn serve(bind: SocketAddr, token: Option<String>, max_age: u64) -> Result<(), AppError> {
+    let state = ServerState {
+        providers: RepoProvider::from_env()?,
+        token,
+        max_age,
+        images: Arc::new(Mutex::new(ImageCache::new(MAX_CACHED_IMAGES))),
+    };
+    let listener = tokio::net::TcpListener::bind(bind)
+        .await
+        .map_err(|e| AppError::io(format!("Failed to listen on {}: {}", bind, e)))?;
+    eprintln!("Serving cards on http://{}/<owner>/<repo>.png", bind);
+    
+    axum::serve(listener, router(state))
+        .await
+        .map_err(|e| AppError::io(format!("Server stopped: {}", e)))
+}
+
+fn router(state: ServerState) -> Router {
+    Router::new()
+        .route("/", get(index))
+        .route("/{*repo}", get(card))
+        .with_state(state)
+}
+
+async fn index() -> &'static str {
+    "RepoCard Studio: GET /<owner>/<repo>.png, .jpg, .webp, .avif or .svg, e.g. /rust-lang/rust.png?template=gradient\n"
+}
+
+async fn card(
+    State(state): State<ServerState>,
+    Path(repo): Path<String>,
+    Query(query): Query<CardQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let accept = headers.get(ACCEPT).and_then(|v| v.to_str().ok());
+    let (repo, format) = match repo.rsplit_once('.').and_then(|(name, ext)| Some((name, ImageFormat::from_extension(ext)?))) {
+        Some((repo, format)) => (repo.to_string(), format),
+        None => (repo, ImageFormat::negotiate(accept)),
+    };
+    // Only forge repositories; never files on the server's own disk
+    if repo.is_empty() || repo.starts_with(['.', '~', '/']) || is_local_path(&repo) {
+        return error_response(AppError::not_found(format!("Not a repository: {}", repo)));
+    }
+    
+    let metadata = match state.providers.fetch_repository_metadata(&repo, state.token.clone()).await {
+        Ok(metadata) => metadata,
+        Err(e) => return error_response(e),
+    };
+    let options = query.export_options(&metadata, format);
+    let width = query.png_width(&options);
+    let key = render_key(&metadata, &options, format, width);
+    let etag = format!("\"{}\"", key);
+    
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
+    response_headers.insert(VARY, HeaderValue::from_static("Accept"));
+    if let Ok(value) = HeaderValue::from_str(&format!("public, max-age={}", state.max_age)) {
+        response_headers.insert(CACHE_CONTROL, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response_headers.insert(ETAG, value);
+    }
+    
+    let not_modified = headers
+        .get(IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|tags| tags.split(',').any(|tag| tag.trim() == etag || tag.trim() == "*"));
+    if not_modified {
+        return (StatusCode::NOT_MODIFIED, response_headers).into_response();
+    }
+    
+    let cached = state.images.lock().ok().and_then(|images| images.get(&key));
+    let image = match cached {
+        Some(image) => image,
+        None => {
+            let rendered = tokio::task::spawn_blocking(move || render_image(&metadata, &options, format, width))
+                .await
+                .map_err(|e| AppError::render(format!("Render task failed: {}", e)))
+                .and_then(|result| result);
+            let image = match rendered {
+                Ok(image) => Arc::new(image),
+                Err(e) => return error_response(e),
+            };
+            if let Ok(mut images) = state.images.lock() {
+                images.insert(key, image.clone());
+            }
+            image
+        }
+    };
+    (StatusCode::OK, response_headers, image.as_ref().clone()).into_response()
+}
+
+/// Identifies one rendering: the same metadata and settings always give the same image,
+/// on every server and release, so it doubles as the ETag
+fn render_key(metadata: &RepoMetadata, options: &ExportOptions, format: ImageFormat, width: u32) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(metadata).unwrap_or_default());
+    hasher.update([0]);
+    hasher.update(serde_json::to_vec(options).unwrap_or_default());
+    hasher.update([0]);
+    hasher.update(format.content_type());
+    if format != ImageFormat::Svg {
+        hasher.update(width.to_be_bytes());
+    }
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn render_image(metadata: &RepoMetadata, options: &ExportOptions, format: ImageFormat, width: u32) -> Result<Vec<u8>, AppError> {
+    let svg = generate_card_svg(metadata, options)?;
+    match format.raster() {
+        None => Ok(svg.into_bytes()),
+        Some(_) => rasterize_svg_as(&svg, width, &options.image),
+    }
+}
+
+/// The error as JSON, with the HTTP status matching its kind
+fn error_response(error: AppError) -> Response {
+    let status = match &error {
+        AppError::NotFound { .. } => StatusCode::NOT_FOUND,
+        AppError::Parse { .. } | AppError::Template { .. } => StatusCode::BAD_REQUEST,
+        AppError::Unauthorized { .. } => StatusCode::BAD_GATEWAY,
+        AppError::RateLimited { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        AppError::Network { .. } => StatusCode::BAD_GATEWAY,
+        AppError::Io { .. } | AppError::Render { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let mut response = (status, Json(&error)).into_response();
+    if let AppError::RateLimited { resets_at, .. } = &error {
+        let seconds = retry_after(resets_at.as_deref(), Utc::now());
+        response.headers_mut().insert(RETRY_AFTER, HeaderValue::from(seconds));
+    }
+    response
+}
+
+/// Seconds until the rate limit resets, at least one
+fn retry_after(resets_at: Option<&str>, now: DateTime<Utc>) -> i64 {
+    resets_at
+        .and_then(|resets_at| DateTime::parse_from_rfc3339(resets_at).ok())
+        .map_or(DEFAULT_RETRY_AFTER, |resets_at| (resets_at.with_timezone(&Utc) - now).num_seconds().max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::extract::rejection::QueryRejection;
+    use axum::http::{Request, Uri};
+    use repocard_core::test_support::sample_metadata;
+    use repocard_core::ProjectConfig;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_negotiates_format() {
+        assert_eq!(ImageFormat::negotiate(None), ImageFormat::Png);
+        assert_eq!(ImageFormat::negotiate(Some("image/svg+xml")), ImageFormat::Svg);
+        assert_eq!(ImageFormat::negotiate(Some("image/png,image/svg+xml;q=0.8")), ImageFormat::Png);
+        assert_eq!(ImageFormat::negotiate(Some("image/svg+xml, image/png;q=0.5")), ImageFormat::Svg);
+        assert_eq!(ImageFormat::negotiate(Some("text/html,*/*;q=0.8")), ImageFormat::Png);
+        assert_eq!(ImageFormat::negotiate(Some("image/avif,image/webp;q=0.9,*/*;q=0.8")), ImageFormat::Avif);
+        assert_eq!(ImageFormat::negotiate(Some("image/webp,image/avif")), ImageFormat::Webp);
+        assert_eq!(ImageFormat::from_extension("jpeg"), Some(ImageFormat::Jpeg));
+        assert_eq!(ImageFormat::from_extension("gif"), None);
+    }
+
+    /// Sample metadata with a `.repocard.toml` of `config`
+    fn configured_metadata(config: &str) -> RepoMetadata {
+        RepoMetadata { project_config: Some(ProjectConfig::parse(config).unwrap()), ..sample_metadata() }
+    }
+
+    fn parse_query(uri: &'static str) -> Result<CardQuery, QueryRejection> {
+        Query::<CardQuery>::try_from_uri(&Uri::from_static(uri)).map(|query| query.0)
+    }
+
+    #[test]
+    fn test_project_config_fills_unset_query_fields() {
+        let metadata = configured_metadata("template = \"minimal\"\nlanguages = true");
+        let options = CardQuery::default().export_options(&metadata, ImageFormat::Png);
+        assert_eq!(options.template_id, "minimal");
+        assert!(options.show_languages);
+    }
+
+    #[test]
+    fn test_query_overrides_project_config() {
+        let metadata = configured_metadata("template = \"minimal\"\nlanguages = true");
+        let query = CardQuery {
+            template: Some("gradient".to_string()),
+            languages: Some(false),
+            quality: Some(70),
+            ..CardQuery::default()
+        };
+        let options = query.export_options(&metadata, ImageFormat::Webp);
+        assert_eq!(options.template_id, "gradient");
+        assert!(!options.show_languages);
+        assert_eq!(options.image.format, RasterFormat::Webp);
+        assert_eq!(options.image.quality, Some(70));
+    }
+
+    #[test]
+    fn test_bounds_quality_and_size_limit() {
+        let query = CardQuery { quality: Some(1), max_kb: Some(1), ..CardQuery::default() };
+        let options = query.export_options(&sample_metadata(), ImageFormat::Avif);
+        assert_eq!((options.image.quality, options.image.max_kb), (Some(MIN_QUALITY), Some(MIN_MAX_KB)));
+        
+        let query = CardQuery { max_kb: Some(u32::MAX), ..CardQuery::default() };
+        assert_eq!(query.export_options(&sample_metadata(), ImageFormat::Avif).image.max_kb, Some(MAX_MAX_KB));
+    }
+
+    #[test]
+    fn test_bounds_size_limit_from_project_config() {
+        let metadata = configured_metadata("[image]\nmax_kb = 1");
+        let options = CardQuery::default().export_options(&metadata, ImageFormat::Avif);
+        assert_eq!(options.image.max_kb, Some(MIN_MAX_KB));
+    }
+
+    #[test]
+    fn test_bounds_png_width() {
+        let options = ExportOptions::default();
+        assert_eq!(CardQuery { width: Some(10_000), ..CardQuery::default() }.png_width(&options), MAX_PNG_WIDTH);
+        assert_eq!(CardQuery { width: Some(1), ..CardQuery::default() }.png_width(&options), MIN_PNG_WIDTH);
+    }
+
+    #[test]
+    fn test_size_query_picks_the_preset_and_width() {
+        let query = parse_query("/owner/repo.png?size=story").unwrap();
+        let options = query.export_options(&sample_metadata(), ImageFormat::Png);
+        assert_eq!(options.size, SizePreset::Story);
+        assert_eq!(query.png_width(&options), 1080);
+        assert!(parse_query("/owner/repo.png?size=banner").is_err());
+    }
+
+    #[test]
+    fn test_render_key_tracks_inputs() {
+        let metadata = sample_metadata();
+        let options = ExportOptions::default();
+        let key = render_key(&metadata, &options, ImageFormat::Png, 1200);
+        assert_eq!(key, render_key(&metadata, &options, ImageFormat::Png, 1200));
+        assert!(key.len() == 64 && key.chars().all(|c| c.is_ascii_hexdigit()), "a SHA-256 digest: {}", key);
+        assert_ne!(key, render_key(&metadata, &options, ImageFormat::Svg, 1200));
+        assert_ne!(key, render_key(&metadata, &options, ImageFormat::Png, 600));
+        
+        let starred = RepoMetadata { stargazers_count: metadata.stargazers_count + 1, ..sample_metadata() };
+        assert_ne!(key, render_key(&starred, &options, ImageFormat::Png, 1200));
+        
+        let jpeg_options = CardQuery::default().export_options(&metadata, ImageFormat::Jpeg);
+        assert_ne!(key, render_key(&metadata, &jpeg_options, ImageFormat::Jpeg, 1200));
+    }
+
+    #[test]
+    fn test_renders_each_format() {
+        let metadata = sample_metadata();
+        let options = ExportOptions::default();
+        let svg = render_image(&metadata, &options, ImageFormat::Svg, 1200).unwrap();
+        assert!(svg.starts_with(b"<!--") || svg.starts_with(b"<svg"));
+        let png = render_image(&metadata, &options, ImageFormat::Png, 600).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        
+        let jpeg_options = CardQuery::default().export_options(&metadata, ImageFormat::Jpeg);
+        let jpeg = render_image(&metadata, &jpeg_options, ImageFormat::Jpeg, 600).unwrap();
+        assert!(jpeg.starts_with(&[0xff, 0xd8, 0xff]));
+    }
+
+    #[test]
+    fn test_refuses_injected_colours() {
+        let metadata = sample_metadata();
+        for uri in ["/owner/repo.svg?primary=%23fff%22%3E%3Cscript%3E", "/owner/repo.svg?secondary=%3Cscript%3E"] {
+            let query = parse_query(uri).unwrap();
+            let options = query.export_options(&metadata, ImageFormat::Svg);
+            let error = render_image(&metadata, &options, ImageFormat::Svg, 1200).unwrap_err();
+            assert_eq!(error.kind(), "parse", "{}", uri);
+            assert_eq!(error_response(error).status(), StatusCode::BAD_REQUEST);
+        }
+        
+        let query = CardQuery { primary: Some("#0d1117".to_string()), ..CardQuery::default() };
+        let options = query.export_options(&metadata, ImageFormat::Svg);
+        assert!(render_image(&metadata, &options, ImageFormat::Svg, 1200).is_ok());
+    }
+
+    #[test]
+    fn test_retry_after_follows_the_reset_time() {
+        let now = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(retry_after(Some("2026-01-01T00:02:30Z"), now), 150);
+        assert_eq!(retry_after(Some("2025-12-31T23:59:00Z"), now), 1);
+        assert_eq!(retry_after(None, now), DEFAULT_RETRY_AFTER);
+        assert_eq!(retry_after(Some("soon"), now), DEFAULT_RETRY_AFTER);
+        
+        let response = error_response(AppError::rate_limited("Rate limited", Some("2999-01-01T00:00:00Z".to_string())));
+        let seconds: i64 = response.headers()[RETRY_AFTER].to_str().unwrap().parse().unwrap();
+        assert!(seconds > DEFAULT_RETRY_AFTER);
+    }
+
+    #[test]
+    fn test_image_cache_evicts_oldest() {
+        let mut cache = ImageCache::new(2);
+        cache.insert("1".to_string(), Arc::new(vec![1]));
+        cache.insert("2".to_string(), Arc::new(vec![2]));
+        cache.insert("1".to_string(), Arc::new(vec![1]));
+        cache.insert("3".to_string(), Arc::new(vec![3]));
+        assert!(cache.get("1").is_none());
+        assert_eq!(cache.get("2").unwrap().as_slice(), [2]);
+        assert_eq!(cache.get("3").unwrap().as_slice(), [3]);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_local_paths() {
+        let state = ServerState {
+            providers: RepoProvider::from_env().unwrap(),
+            token: None,
+            max_age: 60,
+            images: Arc::new(Mutex::new(ImageCache::new(4))),
+        };
+        let app = router(state);
+        
+        let response = app.clone().oneshot(Request::get("/").body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        
+        for path in ["/~/projects/app.png", "/./secret.svg", "//etc.png"] {
+            let response = app.clone().oneshot(Request::get(path).body(Body::empty()).unwrap()).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", path);
+            let body = to_bytes(response.into_body(), 1024).await.unwrap();
+            assert!(String::from_utf8_lossy(&body).contains("\"kind\":\"not_found\""));
+        }
+    }
+}
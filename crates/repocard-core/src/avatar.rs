@@ -0,0 +1,254 @@
+// RepoCard Studio - Avatar Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::cache::{CacheEntry, ResponseCache};
+use crate::error::AppError;
+use crate::provider::USER_AGENT;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{Client, StatusCode, Url};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const AVATAR_TIMEOUT_SECS: u64 = 10;
+/// Avatars larger than this are not worth embedding in a card
+const MAX_AVATAR_BYTES: usize = 1024 * 1024;
+/// Pixel size requested from avatar services that can resize; twice the 80px card avatar
+const AVATAR_SIZE: u32 = 160;
+
+/// Downloads avatars and keeps them as `data:` URIs so cards can embed them,
+/// in memory and, when given a disk cache, across restarts and offline.
+///
+/// Cheap to clone; clones share the cache.
+#[derive(Debug, Clone)]
+pub struct AvatarCache {
+    http: Client,
+    /// Data URIs keyed by the avatar URL they were downloaded from
+    entries: Arc<Mutex<HashMap<String, String>>>,
+    disk: Option<ResponseCache>,
+    /// Revalidate avatars on disk even while they are fresh
+    refresh: bool,
+}
+
+impl AvatarCache {
+    pub fn new(disk: Option<ResponseCache>) -> Result<Self, AppError> {
+        let http = Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(AVATAR_TIMEOUT_SECS))
+            .build()
+            .map_err(|e| AppError::network(format!("Failed to create HTTP client: {}", e)))?;
+        
+        Ok(Self {
+            http,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            disk,
+            refresh: false,
+        })
+    }
+
+    /// A copy sharing this cache that revalidates avatars stored on disk
+    pub fn refreshing(&self) -> Self {
+        Self {
+            refresh: true,
+            ..self.clone()
+        }
+    }
+
+    /// The avatar at `url` as a `data:` URI, downloading it on first use.
+    /// `None` when there is no avatar or it cannot be fetched, e.g. offline.
+    pub async fn data_uri(&self, url: &str) -> Option<String> {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return None;
+        }
+        if !self.refresh {
+            if let Some(cached) = self.entries.lock().ok().and_then(|e| e.get(url).cloned()) {
+                return Some(cached);
+            }
+        }
+        
+        let bytes = self.fetch(url).await?;
+        let data_uri = image_data_uri(&bytes)?;
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(url.to_string(), data_uri.clone());
+        }
+        Some(data_uri)
+    }
+
+    /// Avatar bytes from the disk cache while fresh, otherwise from the server,
+    /// falling back to the last copy on disk when the server cannot be reached
+    async fn fetch(&self, url: &str) -> Option<Vec<u8>> {
+        let Some(disk) = &self.disk else {
+            return self.download(url, None).await.ok().flatten().map(|(body, _)| body);
+        };
+        let key = ResponseCache::key(url, None);
+        let cached = disk.load(&key);
+        if let Some(entry) = cached.as_ref().filter(|e| !self.refresh && disk.is_fresh(e)) {
+            return Some(entry.body.clone());
+        }
+        
+        match self.download(url, cached.as_ref().and_then(|e| e.etag.as_deref())).await {
+            Ok(Some((body, etag))) => {
+                let entry = CacheEntry {
+                    url: url.to_string(),
+                    etag,
+                    fetched_at: Utc::now().timestamp(),
+                    headers: Vec::new(),
+                    body,
+                };
+                let _ = disk.store(&key, &entry);
+                Some(entry.body)
+            }
+            // Not modified: the copy on disk is current again
+            Ok(None) => cached.map(|mut entry| {
+                disk.touch(&key, &mut entry);
+                entry.body
+            }),
+            Err(_) => cached.map(|entry| entry.body),
+        }
+    }
+
+    /// Download an avatar, or `None` when the server answers 304 to `etag`
+    async fn download(&self, url: &str, etag: Option<&str>) -> Result<Option<(Vec<u8>, Option<String>)>, AppError> {
+        let request = self.http.get(sized_avatar_url(url));
+        let request = match etag {
+            Some(etag) => request.header(IF_NONE_MATCH, etag),
+            None => request,
+        };
+        let response = request.send().await.map_err(|e| AppError::network(format!("Failed to download avatar: {}", e)))?;
+        if etag.is_some() && response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        let response = response
+            .error_for_status()
+            .map_err(|e| AppError::network(format!("Failed to download avatar: {}", e)))?;
+        
+        let etag = response.headers().get(ETAG).and_then(|v| v.to_str().ok()).map(str::to_string);
+        if response.content_length().is_some_and(|len| len > MAX_AVATAR_BYTES as u64) {
+            return Err(AppError::render("Avatar is too large to embed"));
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| AppError::network(format!("Failed to download avatar: {}", e)))?;
+        if bytes.len() > MAX_AVATAR_BYTES {
+            return Err(AppError::render("Avatar is too large to embed"));
+        }
+        Ok(Some((bytes.to_vec(), etag)))
+    }
+}
+
+/// Encode image bytes as a `data:` URI, for the formats resvg can draw
+pub fn image_data_uri(bytes: &[u8]) -> Option<String> {
+    let mime = sniff_image_type(bytes)?;
+    Some(format!("data:{};base64,{}", mime, STANDARD.encode(bytes)))
+}
+
+/// Image type from the file signature; servers are not always right about `Content-Type`
+fn sniff_image_type(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+/// Ask avatar services that resize on request for a card-sized image
+fn sized_avatar_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+    let resizable = matches!(
+        parsed.host_str(),
+        Some("avatars.githubusercontent.com") | Some("secure.gravatar.com") | Some("www.gravatar.com")
+    );
+    if !resizable {
+        return url.to_string();
+    }
+    
+    let query: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| key != "s" && key != "size")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    parsed
+        .query_pairs_mut()
+        .clear()
+        .extend_pairs(query)
+        .append_pair("s", &AVATAR_SIZE.to_string());
+    parsed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    #[test]
+    fn test_image_data_uri() {
+        let uri = image_data_uri(PNG_SIGNATURE).unwrap();
+        assert_eq!(uri, format!("data:image/png;base64,{}", STANDARD.encode(PNG_SIGNATURE)));
+        assert!(image_data_uri(&[0xff, 0xd8, 0xff, 0xe0]).unwrap().starts_with("data:image/jpeg;"));
+        assert!(image_data_uri(b"GIF89a....").unwrap().starts_with("data:image/gif;"));
+        assert!(image_data_uri(b"RIFF\0\0\0\0WEBPVP8 ").unwrap().starts_with("data:image/webp;"));
+        assert!(image_data_uri(b"<html>Not found</html>").is_none());
+    }
+
+    #[test]
+    fn test_sized_avatar_url() {
+        assert_eq!(
+            sized_avatar_url("https://avatars.githubusercontent.com/u/1?v=4"),
+            "https://avatars.githubusercontent.com/u/1?v=4&s=160"
+        );
+        assert_eq!(
+            sized_avatar_url("https://avatars.githubusercontent.com/u/1?s=460&v=4"),
+            "https://avatars.githubusercontent.com/u/1?v=4&s=160"
+        );
+        assert_eq!(
+            sized_avatar_url("https://gitlab.com/uploads/-/system/user/avatar/1/avatar.png"),
+            "https://gitlab.com/uploads/-/system/user/avatar/1/avatar.png"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_data_uri_is_cached() {
+        // Serve one avatar, then go away: the second lookup must not need the network
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/avatar.png", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+            let header = format!("HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", PNG_SIGNATURE.len());
+            socket.write_all(header.as_bytes()).await.unwrap();
+            socket.write_all(PNG_SIGNATURE).await.unwrap();
+        });
+        
+        let dir = tempfile::TempDir::new().unwrap();
+        let disk = ResponseCache::new(dir.path(), Duration::from_secs(60));
+        let avatars = AvatarCache::new(Some(disk.clone())).unwrap();
+        let first = avatars.data_uri(&url).await.unwrap();
+        server.await.unwrap();
+        assert!(first.starts_with("data:image/png;base64,"));
+        assert_eq!(avatars.clone().data_uri(&url).await, Some(first.clone()));
+        
+        // A fresh process finds it on disk; a forced refresh falls back to it offline
+        let restarted = AvatarCache::new(Some(disk)).unwrap();
+        assert_eq!(restarted.data_uri(&url).await, Some(first.clone()));
+        assert_eq!(restarted.refreshing().data_uri(&url).await, Some(first));
+        
+        assert_eq!(avatars.data_uri("").await, None);
+        assert_eq!(avatars.data_uri("http://127.0.0.1:1/missing.png").await, None);
+    }
+}
@@ -0,0 +1,180 @@
+// RepoCard Studio - Response Cache Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long API responses are served without asking the server again
+pub const API_CACHE_TTL_SECS: u64 = 10 * 60;
+/// Avatars change rarely
+pub const AVATAR_CACHE_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+const CACHE_DIR_NAME: &str = "repocard-studio";
+
+/// A response as it was last received, with the validator to revalidate it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub url: String,
+    pub etag: Option<String>,
+    /// Unix time the server last confirmed the body, by a 200 or a 304
+    pub fetched_at: i64,
+    /// Response headers worth replaying, such as `Content-Type` and `Link`
+    pub headers: Vec<(String, String)>,
+    /// Stored beside the entry rather than inside it, so binary bodies stay compact
+    #[serde(skip)]
+    pub body: Vec<u8>,
+}
+
+/// On-disk store of response bodies keyed by request, with a freshness TTL.
+///
+/// Each entry is a `<key>.json` description plus a `<key>.body` file.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self { dir: dir.into(), ttl }
+    }
+
+    /// Cache under the app data dir, e.g. `~/.local/share/repocard-studio/cache/<name>`
+    pub fn in_data_dir(name: &str, ttl: Duration) -> Option<Self> {
+        dirs::data_dir().map(|dir| Self::new(dir.join(CACHE_DIR_NAME).join("cache").join(name), ttl))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Key for a request. Responses fetched with different credentials never share an entry.
+    pub fn key(url: &str, credential: Option<&str>) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(url.as_bytes());
+        if let Some(credential) = credential {
+            hasher.update([0]);
+            hasher.update(credential.as_bytes());
+        }
+        hex(&hasher.finalize())
+    }
+
+    /// The stored entry, fresh or stale, if there is a readable one
+    pub fn load(&self, key: &str) -> Option<CacheEntry> {
+        let meta = fs::read(self.dir.join(format!("{}.json", key))).ok()?;
+        let mut entry: CacheEntry = serde_json::from_slice(&meta).ok()?;
+        entry.body = fs::read(self.dir.join(format!("{}.body", key))).ok()?;
+        Some(entry)
+    }
+
+    /// Write an entry. The description goes last, so a torn write is never loaded.
+    pub fn store(&self, key: &str, entry: &CacheEntry) -> Result<(), AppError> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| AppError::io(format!("Failed to create cache directory: {}", e)))?;
+        write_atomic(&self.dir.join(format!("{}.body", key)), &entry.body)?;
+        let meta = serde_json::to_vec(entry).map_err(|e| AppError::parse(format!("Failed to encode cache entry: {}", e)))?;
+        write_atomic(&self.dir.join(format!("{}.json", key)), &meta)
+    }
+
+    /// Record that the server confirmed an entry is still current (a 304)
+    pub fn touch(&self, key: &str, entry: &mut CacheEntry) {
+        entry.fetched_at = Utc::now().timestamp();
+        if let Ok(meta) = serde_json::to_vec(entry) {
+            let _ = write_atomic(&self.dir.join(format!("{}.json", key)), &meta);
+        }
+    }
+
+    /// Whether an entry is young enough to use without revalidating
+    pub fn is_fresh(&self, entry: &CacheEntry) -> bool {
+        let age = Utc::now().timestamp().saturating_sub(entry.fetched_at);
+        age >= 0 && (age as u64) < self.ttl.as_secs()
+    }
+
+    /// Remove every cached entry
+    pub fn clear(&self) -> Result<(), AppError> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(AppError::io(format!("Failed to clear cache: {}", e)))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), AppError> {
+    let partial = path.with_extension("partial");
+    fs::write(&partial, contents)
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|e| AppError::io(format!("Failed to write cache entry: {}", e)))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(body: &[u8]) -> CacheEntry {
+        CacheEntry {
+            url: "https://api.github.com/repos/a/b".to_string(),
+            etag: Some("W/\"abc\"".to_string()),
+            fetched_at: Utc::now().timestamp(),
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: body.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_store_and_load() {
+        let dir = TempDir::new().unwrap();
+        let cache = ResponseCache::new(dir.path().join("api"), Duration::from_secs(60));
+        let key = ResponseCache::key("https://api.github.com/repos/a/b", None);
+        assert!(cache.load(&key).is_none());
+        
+        cache.store(&key, &entry(b"{\"name\":\"b\"}")).unwrap();
+        let loaded = cache.load(&key).unwrap();
+        assert_eq!(loaded.body, b"{\"name\":\"b\"}");
+        assert_eq!(loaded.etag.as_deref(), Some("W/\"abc\""));
+        assert!(cache.is_fresh(&loaded));
+        
+        cache.clear().unwrap();
+        assert!(cache.load(&key).is_none());
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_ttl_and_touch() {
+        let dir = TempDir::new().unwrap();
+        let cache = ResponseCache::new(dir.path(), Duration::from_secs(60));
+        let key = ResponseCache::key("https://example.com", None);
+        let mut stale = CacheEntry {
+            fetched_at: Utc::now().timestamp() - 120,
+            ..entry(b"old")
+        };
+        cache.store(&key, &stale).unwrap();
+        assert!(!cache.is_fresh(&cache.load(&key).unwrap()));
+        
+        cache.touch(&key, &mut stale);
+        assert!(cache.is_fresh(&cache.load(&key).unwrap()));
+        assert!(!ResponseCache::new(dir.path(), Duration::ZERO).is_fresh(&stale));
+    }
+
+    #[test]
+    fn test_keys_separate_credentials() {
+        let url = "https://api.github.com/repos/a/b";
+        let anonymous = ResponseCache::key(url, None);
+        assert_eq!(anonymous.len(), 64);
+        assert_eq!(anonymous, ResponseCache::key(url, None));
+        assert_ne!(anonymous, ResponseCache::key(url, Some("token-a")));
+        assert_ne!(ResponseCache::key(url, Some("token-a")), ResponseCache::key(url, Some("token-b")));
+        assert_ne!(anonymous, ResponseCache::key("https://api.github.com/repos/a/c", None));
+    }
+}
@@ -0,0 +1,229 @@
+// RepoCard Studio - Project Config Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::{ExportOptions, RasterOptions, ReleaseNoteCategory, RepoMetadata, ShareKitLayout, SizePreset};
+use serde::{Deserialize, Serialize};
+
+/// Checked-in file a repository declares its card and share kit preferences in
+pub const PROJECT_CONFIG_FILE: &str = ".repocard.toml";
+
+/// Contents of `.repocard.toml`. Every key is optional; unset keys keep the app's defaults.
+///
+/// ```toml
+/// template = "gradient"
+/// tagline = "Social cards for your repositories"
+/// topics = ["rust", "tauri"]
+/// attribution = false
+/// languages = true
+/// size = "twitter"
+/// all_sizes = true
+///
+/// [colors]
+/// primary = "#0f172a"
+/// secondary = "#38bdf8"
+///
+/// [[release_notes.categories]]
+/// title = "🚀 Features"
+/// prefixes = ["feat"]
+///
+/// [output]
+/// folder = "assets/share"
+///
+/// [image]
+/// format = "webp"
+/// max_kb = 300
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub template: Option<String>,
+    /// Shown on cards and in the press kit instead of the repository description
+    pub tagline: Option<String>,
+    /// Topics to feature, in order, instead of every repository topic
+    pub topics: Vec<String>,
+    pub attribution: Option<bool>,
+    pub contributors: Option<bool>,
+    pub languages: Option<bool>,
+    /// Export the SVG card with text converted to paths
+    pub outline_text: Option<bool>,
+    /// Platform size of the card: `og`, `twitter`, `linkedin`, `square`, `story` or `github`
+    pub size: Option<SizePreset>,
+    /// Also export the card in every size
+    pub all_sizes: Option<bool>,
+    pub colors: ColorConfig,
+    pub release_notes: ReleaseNotesConfig,
+    /// Where the share kit files go; unset fields keep the standard names
+    pub output: Option<ShareKitLayout>,
+    /// Format and size limit of the raster card
+    pub image: Option<RasterOptions>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColorConfig {
+    pub primary: Option<String>,
+    pub secondary: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReleaseNotesConfig {
+    /// Sections commits are sorted into by message prefix, replacing the built-in ones
+    pub categories: Vec<ReleaseNoteCategory>,
+}
+
+impl ProjectConfig {
+    pub fn parse(source: &str) -> Result<Self, AppError> {
+        toml::from_str(source).map_err(|e| AppError::parse(format!("Invalid {}: {}", PROJECT_CONFIG_FILE, e)))
+    }
+
+    /// Apply the tagline and featured topics, and keep the config for the UI
+    pub fn apply_to_metadata(self, metadata: &mut RepoMetadata) {
+        if let Some(tagline) = self.tagline.as_ref().filter(|t| !t.trim().is_empty()) {
+            metadata.description = Some(tagline.trim().to_string());
+        }
+        if !self.topics.is_empty() {
+            metadata.topics = self.topics.clone();
+        }
+        metadata.project_config = Some(self);
+    }
+
+    /// Merge the settings this config declares over `options`
+    pub fn apply_to_options(&self, options: &mut ExportOptions) {
+        if let Some(template) = &self.template {
+            options.template_id = template.clone();
+        }
+        if let Some(attribution) = self.attribution {
+            options.include_attribution = attribution;
+        }
+        if let Some(contributors) = self.contributors {
+            options.show_contributors = contributors;
+        }
+        if let Some(languages) = self.languages {
+            options.show_languages = languages;
+        }
+        if self.colors.primary.is_some() {
+            options.primary_color = self.colors.primary.clone();
+        }
+        if self.colors.secondary.is_some() {
+            options.secondary_color = self.colors.secondary.clone();
+        }
+        self.apply_non_ui_options(options);
+    }
+
+    /// Merge only the settings the app has no controls for, keeping what the user picked in it
+    pub fn apply_non_ui_options(&self, options: &mut ExportOptions) {
+        if let Some(outline_text) = self.outline_text {
+            options.outline_text = outline_text;
+        }
+        if let Some(size) = self.size {
+            options.size = size;
+        }
+        if let Some(all_sizes) = self.all_sizes {
+            options.all_sizes = all_sizes;
+        }
+        if !self.release_notes.categories.is_empty() {
+            options.release_note_categories = self.release_notes.categories.clone();
+        }
+        if let Some(layout) = &self.output {
+            options.layout = layout.clone();
+        }
+        if let Some(image) = &self.image {
+            options.image = image.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r##"
+template = "gradient"
+tagline = "Cards for every repo"
+topics = ["rust", "cli"]
+attribution = false
+languages = true
+outline_text = true
+size = "story"
+
+[colors]
+primary = "#0f172a"
+
+[[release_notes.categories]]
+title = "🚀 Features"
+prefixes = ["feat", "add"]
+
+[output]
+folder = "assets/share"
+readme = "snippet.md"
+
+[image]
+format = "jpeg"
+max_kb = 300
+"##;
+
+    #[test]
+    fn test_merges_over_export_defaults() {
+        let config = ProjectConfig::parse(SAMPLE).unwrap();
+        let mut options = ExportOptions::default();
+        config.apply_to_options(&mut options);
+
+        assert_eq!(options.template_id, "gradient");
+        assert!(!options.include_attribution);
+        assert!(options.show_languages);
+        assert!(!options.show_contributors);
+        assert!(options.outline_text);
+        assert_eq!(options.size, SizePreset::Story);
+        assert!(!options.all_sizes);
+        assert_eq!(options.primary_color.as_deref(), Some("#0f172a"));
+        assert_eq!(options.secondary_color, None);
+        assert_eq!(options.release_note_categories[0].prefixes, vec!["feat", "add"]);
+        assert_eq!(options.layout.folder, "assets/share");
+        assert_eq!(options.layout.readme, "snippet.md");
+        assert_eq!(options.layout.card, ShareKitLayout::default().card);
+        assert_eq!(options.image.format, crate::ImageFormat::Jpeg);
+        assert_eq!(options.image.max_kb, Some(300));
+        assert_eq!(options.image.quality, None);
+    }
+
+    #[test]
+    fn test_non_ui_options_keep_the_apps_choices() {
+        let config = ProjectConfig::parse(SAMPLE).unwrap();
+        let mut options = ExportOptions {
+            template_id: "minimal".to_string(),
+            show_contributors: true,
+            ..ExportOptions::default()
+        };
+        config.apply_non_ui_options(&mut options);
+
+        assert_eq!(options.template_id, "minimal");
+        assert!(options.include_attribution);
+        assert!(options.show_contributors);
+        assert!(!options.show_languages);
+        assert_eq!(options.primary_color, None);
+        assert!(options.outline_text);
+        assert_eq!(options.size, SizePreset::Story);
+        assert_eq!(options.release_note_categories[0].title, "🚀 Features");
+        assert_eq!(options.layout.folder, "assets/share");
+        assert_eq!(options.image.max_kb, Some(300));
+    }
+
+    #[test]
+    fn test_empty_config_changes_nothing() {
+        let mut options = ExportOptions::default();
+        ProjectConfig::parse("").unwrap().apply_to_options(&mut options);
+        let defaults = ExportOptions::default();
+        assert_eq!(options.template_id, defaults.template_id);
+        assert_eq!(options.include_attribution, defaults.include_attribution);
+        assert_eq!(options.layout, defaults.layout);
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys() {
+        let error = ProjectConfig::parse("templat = \"modern\"").unwrap_err();
+        assert_eq!(error.kind(), "parse");
+        assert!(error.message().starts_with("Invalid .repocard.toml"));
+    }
+}
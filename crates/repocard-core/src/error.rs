@@ -0,0 +1,143 @@
+// RepoCard Studio - Error Module
+// LAZYFROG (KZ) — kindware.dev
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Error returned by every backend function and Tauri command.
+///
+/// Serializes as `{ "kind": "not_found", "message": "..." }` so the UI can react per kind.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AppError {
+    /// The repository, ref, release or local checkout does not exist
+    NotFound { message: String },
+    /// The provider's rate limit is used up until `resets_at`, when known
+    RateLimited { message: String, resets_at: Option<String> },
+    /// The token is missing, invalid or lacks access
+    Unauthorized { message: String },
+    /// The request never got a usable answer: offline, timed out or a server error
+    Network { message: String },
+    /// Input or a response could not be understood
+    Parse { message: String },
+    /// Reading or writing local files or git history failed
+    Io { message: String },
+    /// No such template, or the template itself is broken
+    Template { message: String },
+    /// SVG or image output could not be produced
+    Render { message: String },
+}
+
+impl AppError {
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound { message: message.into() }
+    }
+
+    pub fn rate_limited(message: impl Into<String>, resets_at: Option<String>) -> Self {
+        Self::RateLimited { message: message.into(), resets_at }
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized { message: message.into() }
+    }
+
+    pub fn network(message: impl Into<String>) -> Self {
+        Self::Network { message: message.into() }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::Parse { message: message.into() }
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::Io { message: message.into() }
+    }
+
+    pub fn template(message: impl Into<String>) -> Self {
+        Self::Template { message: message.into() }
+    }
+
+    pub fn render(message: impl Into<String>) -> Self {
+        Self::Render { message: message.into() }
+    }
+
+    /// The `kind` tag the error serializes with
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NotFound { .. } => "not_found",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::Unauthorized { .. } => "unauthorized",
+            Self::Network { .. } => "network",
+            Self::Parse { .. } => "parse",
+            Self::Io { .. } => "io",
+            Self::Template { .. } => "template",
+            Self::Render { .. } => "render",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::NotFound { message }
+            | Self::RateLimited { message, .. }
+            | Self::Unauthorized { message }
+            | Self::Network { message }
+            | Self::Parse { message }
+            | Self::Io { message }
+            | Self::Template { message }
+            | Self::Render { message } => message,
+        }
+    }
+
+    fn message_mut(&mut self) -> &mut String {
+        match self {
+            Self::NotFound { message }
+            | Self::RateLimited { message, .. }
+            | Self::Unauthorized { message }
+            | Self::Network { message }
+            | Self::Parse { message }
+            | Self::Io { message }
+            | Self::Template { message }
+            | Self::Render { message } => message,
+        }
+    }
+
+    /// Prefix the message with what was being attempted, keeping the kind
+    pub fn context(mut self, context: impl fmt::Display) -> Self {
+        let message = self.message_mut();
+        *message = format!("{}: {}", context, message);
+        self
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_with_kind_tag() {
+        let error = AppError::not_found("Repository not found: octocat/missing");
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json, serde_json::json!({ "kind": "not_found", "message": "Repository not found: octocat/missing" }));
+        
+        let error = AppError::rate_limited("GitHub API rate limit exceeded", Some("2026-01-01T00:00:00Z".to_string()));
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["kind"], "rate_limited");
+        assert_eq!(json["resets_at"], "2026-01-01T00:00:00Z");
+        assert_eq!(serde_json::from_value::<AppError>(json).unwrap(), error);
+    }
+
+    #[test]
+    fn test_context_keeps_kind() {
+        let error = AppError::io("permission denied").context("Failed to write SVG");
+        assert_eq!(error.kind(), "io");
+        assert_eq!(error.to_string(), "Failed to write SVG: permission denied");
+    }
+}
@@ -0,0 +1,258 @@
+// RepoCard Studio - Export Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::layout::outline_text;
+use crate::raster::rasterize_svg_as;
+use crate::{CommitInfo, ExportOptions, ExportResult, RepoMetadata, SizePreset};
+use crate::templates;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Export full share kit to filesystem, laid out as `options.layout` says
+pub async fn export_full_share_kit(
+    metadata: &RepoMetadata,
+    commits: &[CommitInfo],
+    options: &ExportOptions,
+) -> Result<ExportResult, AppError> {
+    let layout = &options.layout;
+    let share_kit_path = kit_path(Path::new(&options.output_dir), &layout.folder)?;
+    // Refuse a bad layout before anything is written, not when its file comes up
+    for name in [&layout.card, &layout.readme, &layout.release_notes, &layout.press_kit, &layout.screenshots] {
+        kit_path(&share_kit_path, name)?;
+    }
+    
+    let mut files: Vec<String> = Vec::new();
+    
+    // Generate and save the SVG and raster card
+    write_card(&share_kit_path, &layout.card, metadata, options, &mut files)?;
+    
+    // The same card in every platform size
+    if options.all_sizes {
+        for size in SizePreset::ALL {
+            let sized = ExportOptions { size, ..options.clone() };
+            write_card(&share_kit_path, &format!("{}-{}", layout.card, size.id()), metadata, &sized, &mut files)?;
+        }
+    }
+    
+    // Generate and save README snippet
+    let readme_snippet = templates::generate_readme_snippet(metadata, options.include_attribution);
+    write_kit_file(&share_kit_path, &layout.readme, readme_snippet.as_bytes(), "README snippet")?;
+    files.push(layout.readme.clone());
+    
+    // Generate and save release notes
+    let release_notes = templates::generate_release_notes_draft(
+        metadata,
+        commits,
+        None,
+        None,
+        &options.release_note_categories,
+        options.include_attribution,
+    );
+    write_kit_file(&share_kit_path, &layout.release_notes, release_notes.as_bytes(), "release notes")?;
+    files.push(layout.release_notes.clone());
+    
+    // Generate and save press kit overview
+    let press_kit = templates::generate_press_kit(metadata, options);
+    write_kit_file(&share_kit_path, &layout.press_kit, press_kit.as_bytes(), "press kit")?;
+    files.push(layout.press_kit.clone());
+    
+    // Create .gitkeep in screenshots folder
+    let gitkeep = format!("{}/.gitkeep", layout.screenshots.trim_end_matches('/'));
+    write_kit_file(&share_kit_path, &gitkeep, b"", ".gitkeep")?;
+    files.push(gitkeep);
+    
+    Ok(ExportResult {
+        success: true,
+        output_path: share_kit_path.to_string_lossy().to_string(),
+        files,
+        error: None,
+    })
+}
+
+/// Write `<name>.svg` and the raster card next to it, at the size `options` asks for
+fn write_card(
+    share_kit_path: &Path,
+    name: &str,
+    metadata: &RepoMetadata,
+    options: &ExportOptions,
+    files: &mut Vec<String>,
+) -> Result<(), AppError> {
+    let svg_content = templates::generate_card_svg(metadata, options)?;
+    let svg_name = format!("{}.svg", name);
+    let svg_file = if options.outline_text { outline_text(&svg_content)? } else { svg_content.clone() };
+    write_kit_file(share_kit_path, &svg_name, svg_file.as_bytes(), "SVG")?;
+    files.push(svg_name);
+    
+    let (width, _) = options.size.dimensions();
+    let image_data = rasterize_svg_as(&svg_content, width, &options.image)?;
+    let image_name = format!("{}.{}", name, options.image.format.extension());
+    write_kit_file(share_kit_path, &image_name, &image_data, "image")?;
+    files.push(image_name);
+    Ok(())
+}
+
+/// Join a layout path onto `base`. Layouts can come from a repository's config,
+/// so anything that would leave `base` is refused.
+fn kit_path(base: &Path, relative: &str) -> Result<PathBuf, AppError> {
+    let relative = Path::new(relative);
+    let inside = relative.components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if !inside || relative.as_os_str().is_empty() {
+        return Err(AppError::parse(format!(
+            "Share kit paths must stay inside the export folder: {}",
+            relative.display()
+        )));
+    }
+    Ok(base.join(relative))
+}
+
+/// Write one share kit file, creating the folders it sits in
+fn write_kit_file(share_kit_path: &Path, name: &str, contents: &[u8], what: &str) -> Result<(), AppError> {
+    let path = kit_path(share_kit_path, name)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| AppError::io(format!("Failed to create directories: {}", e)))?;
+    }
+    fs::write(&path, contents)
+        .map_err(|e| AppError::io(format!("Failed to write {}: {}", what, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metadata;
+    use crate::{ImageFormat, RasterOptions, ShareKitLayout};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_export_creates_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let metadata = sample_metadata();
+        let commits = vec![
+            CommitInfo {
+                sha: "abc1234".to_string(),
+                message: "feat: initial commit".to_string(),
+                author_name: "Test".to_string(),
+                author_email: "test@example.com".to_string(),
+                date: "2024-01-01T00:00:00Z".to_string(),
+                author_login: None,
+            },
+        ];
+        
+        let options = ExportOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            include_attribution: true,
+            template_id: "modern".to_string(),
+            primary_color: None,
+            secondary_color: None,
+            show_contributors: false,
+            show_languages: false,
+            outline_text: false,
+            size: SizePreset::default(),
+            all_sizes: false,
+            image: RasterOptions::default(),
+            release_note_categories: Vec::new(),
+            layout: ShareKitLayout::default(),
+        };
+        
+        let result = export_full_share_kit(&metadata, &commits, &options).await;
+        assert!(result.is_ok());
+        
+        let result = result.unwrap();
+        assert!(result.success);
+        assert!(!result.files.is_empty());
+        
+        // Verify files exist
+        let share_kit = temp_dir.path().join("share-kit");
+        assert!(share_kit.join("repo-card.svg").exists());
+        assert!(share_kit.join("repo-card.png").exists());
+        assert!(share_kit.join("README-snippet.md").exists());
+        assert!(share_kit.join("release-notes-draft.md").exists());
+        assert!(share_kit.join("press-kit/overview.md").exists());
+        assert!(share_kit.join("press-kit/screenshots/.gitkeep").exists());
+    }
+
+    #[tokio::test]
+    async fn test_export_follows_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let options = ExportOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            layout: ShareKitLayout {
+                folder: "assets/social".to_string(),
+                card: "card".to_string(),
+                press_kit: "press.md".to_string(),
+                ..ShareKitLayout::default()
+            },
+            ..ExportOptions::default()
+        };
+        let result = export_full_share_kit(&sample_metadata(), &[], &options).await.unwrap();
+        let share_kit = temp_dir.path().join("assets/social");
+        assert!(share_kit.join("card.svg").exists());
+        assert!(share_kit.join("card.png").exists());
+        assert!(share_kit.join("press.md").exists());
+        assert!(result.files.contains(&"README-snippet.md".to_string()));
+        assert!(fs::read_to_string(share_kit.join("card.svg")).unwrap().contains("<text"));
+    }
+
+    #[tokio::test]
+    async fn test_export_outlines_text() {
+        let temp_dir = TempDir::new().unwrap();
+        let options = ExportOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            outline_text: true,
+            ..ExportOptions::default()
+        };
+        export_full_share_kit(&sample_metadata(), &[], &options).await.unwrap();
+        let outlined = fs::read_to_string(temp_dir.path().join("share-kit/repo-card.svg")).unwrap();
+        assert!(!outlined.contains("<text"));
+        assert!(usvg::Tree::from_str(&outlined, &usvg::Options::default()).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_image_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let image = RasterOptions { format: ImageFormat::Webp, quality: Some(80), max_kb: None };
+        let options = ExportOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            image,
+            ..ExportOptions::default()
+        };
+        let result = export_full_share_kit(&sample_metadata(), &[], &options).await.unwrap();
+        let share_kit = temp_dir.path().join("share-kit");
+        assert!(result.files.contains(&"repo-card.webp".to_string()));
+        assert!(!share_kit.join("repo-card.png").exists());
+        assert!(fs::read(share_kit.join("repo-card.webp")).unwrap().starts_with(b"RIFF"));
+    }
+
+    #[tokio::test]
+    async fn test_export_all_sizes() {
+        let temp_dir = TempDir::new().unwrap();
+        let options = ExportOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            all_sizes: true,
+            ..ExportOptions::default()
+        };
+        let result = export_full_share_kit(&sample_metadata(), &[], &options).await.unwrap();
+        let share_kit = temp_dir.path().join("share-kit");
+        for size in SizePreset::ALL {
+            assert!(result.files.contains(&format!("repo-card-{}.svg", size.id())), "{:?}", size);
+            let png = fs::read(share_kit.join(format!("repo-card-{}.png", size.id()))).unwrap();
+            let (width, height) = size.dimensions();
+            assert_eq!(&png[16..24], [width.to_be_bytes(), height.to_be_bytes()].concat().as_slice(), "{:?}", size);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_escaping_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut options = ExportOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            all_sizes: true,
+            ..ExportOptions::default()
+        };
+        options.layout.readme = "../../escape.md".to_string();
+        let error = export_full_share_kit(&sample_metadata(), &[], &options).await.unwrap_err();
+        assert_eq!(error.kind(), "parse");
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0, "nothing is written for a rejected layout");
+    }
+}
@@ -0,0 +1,489 @@
+// RepoCard Studio - Gitea/Forgejo API Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::provider::{format_range_date, AccessToken, ApiClient, ProviderKind, ServerConfig};
+use crate::repo_ref::RepoRef;
+use crate::{CommitInfo, CommitRange, ContributorInfo, LicenseInfo, OwnerInfo, RateLimitStatus, RepoMetadata};
+use crate::{language_breakdown, LanguageStat, ReleaseAsset, ReleaseInfo, TagInfo};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Items per page; Gitea's default `MAX_RESPONSE_ITEMS`
+const PAGE_SIZE: usize = 50;
+/// Recent commits tallied to rank contributors
+const CONTRIBUTOR_COMMIT_SCAN: u32 = 300;
+
+/// Long-lived client for the Gitea-compatible API (Gitea, Forgejo, Codeberg).
+///
+/// Cheap to clone; clones share the connection pool and rate limit state.
+#[derive(Debug, Clone)]
+pub struct GiteaClient {
+    api: ApiClient,
+    config: ServerConfig,
+}
+
+/// Parse owner and repo from a Gitea URL on the given host
+fn parse_repo_url(url: &str, host: &str) -> Result<(String, String), AppError> {
+    let invalid = || AppError::parse(format!("Invalid Gitea URL or repo format: {}", url.trim()));
+    let repo_ref = RepoRef::parse(url).map_err(|_| invalid())?;
+    
+    if repo_ref.host != host || repo_ref.owner.contains('/') {
+        return Err(invalid());
+    }
+    Ok((repo_ref.owner, repo_ref.repo))
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRepoResponse {
+    name: String,
+    full_name: String,
+    description: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    stars_count: u32,
+    #[serde(default)]
+    forks_count: u32,
+    #[serde(default)]
+    watchers_count: u32,
+    #[serde(default)]
+    open_issues_count: u32,
+    language: Option<String>,
+    /// Only included by recent Gitea/Forgejo releases
+    topics: Option<Vec<String>>,
+    /// SPDX identifiers detected by Gitea 1.22+ / Forgejo 8+
+    #[serde(default)]
+    licenses: Vec<String>,
+    created_at: String,
+    updated_at: String,
+    default_branch: String,
+    owner: GiteaOwner,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaOwner {
+    login: String,
+    avatar_url: String,
+    /// Missing on older servers
+    html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTopics {
+    topics: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaCommit {
+    sha: String,
+    commit: GiteaCommitDetails,
+    /// Gitea account matched to the commit email, if any
+    author: Option<GiteaOwner>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaCommitDetails {
+    message: String,
+    author: GiteaCommitAuthor,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaCommitAuthor {
+    name: String,
+    email: String,
+    date: String,
+}
+
+/// Same shape as GitHub's release objects
+#[derive(Debug, Deserialize)]
+struct GiteaRelease {
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+    html_url: String,
+    published_at: Option<String>,
+    draft: bool,
+    prerelease: bool,
+    #[serde(default)]
+    assets: Vec<GiteaAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaAsset {
+    name: String,
+    size: u64,
+    download_count: u64,
+    browser_download_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTag {
+    name: String,
+    commit: GiteaTagCommit,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTagCommit {
+    sha: String,
+}
+
+fn release_info(r: GiteaRelease) -> ReleaseInfo {
+    ReleaseInfo {
+        tag_name: r.tag_name,
+        name: r.name.filter(|n| !n.trim().is_empty()),
+        body: r.body.filter(|b| !b.trim().is_empty()),
+        html_url: r.html_url,
+        published_at: r.published_at,
+        draft: r.draft,
+        prerelease: r.prerelease,
+        assets: r
+            .assets
+            .into_iter()
+            .map(|a| ReleaseAsset {
+                name: a.name,
+                size: a.size,
+                download_count: a.download_count,
+                download_url: a.browser_download_url,
+            })
+            .collect(),
+    }
+}
+
+/// Gitea only reports SPDX ids, so derive the remaining licence fields from them
+fn license_from_spdx(spdx_id: &str) -> LicenseInfo {
+    LicenseInfo {
+        key: spdx_id.to_lowercase(),
+        name: spdx_id.to_string(),
+        spdx_id: Some(spdx_id.to_string()),
+    }
+}
+
+impl GiteaClient {
+    pub fn new(config: ServerConfig) -> Result<Self, AppError> {
+        let api = ApiClient::new(ProviderKind::Gitea, &config, "application/json")?;
+        Ok(Self { api, config })
+    }
+
+    pub fn config(&self) -> &ServerConfig {
+        &self.config
+    }
+
+    /// A client sharing this one's connections that revalidates cached responses
+    pub fn refreshing(&self) -> Self {
+        Self {
+            api: self.api.refreshing(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether repository URLs on this host belong to this instance
+    pub fn handles_host(&self, host: &str) -> bool {
+        host == self.config.host
+    }
+
+    /// Rate limit state reported by the most recent response, if any
+    pub fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        self.api.last_rate_limit()
+    }
+
+    fn web_base(&self) -> &str {
+        self.config.api_base.trim_end_matches("/api/v1")
+    }
+
+    /// Fetch repository metadata and map it onto `RepoMetadata`
+    pub async fn fetch_repository_metadata(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<RepoMetadata, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}", owner, repo);
+        let response = self.api.send_checked(self.api.get(&path, token), token).await?;
+        
+        let gitea_repo: GiteaRepoResponse = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse Gitea response: {}", e)))?;
+        
+        let topics = match gitea_repo.topics {
+            Some(topics) => topics,
+            None => self.fetch_topics(&owner, &repo, token).await,
+        };
+        
+        Ok(RepoMetadata {
+            name: gitea_repo.name,
+            full_name: gitea_repo.full_name,
+            description: gitea_repo.description.filter(|d| !d.trim().is_empty()),
+            html_url: gitea_repo.html_url,
+            stargazers_count: gitea_repo.stars_count,
+            forks_count: gitea_repo.forks_count,
+            watchers_count: gitea_repo.watchers_count,
+            open_issues_count: gitea_repo.open_issues_count,
+            language: gitea_repo.language.filter(|l| !l.is_empty()),
+            topics,
+            created_at: gitea_repo.created_at,
+            // Gitea has no separate push timestamp
+            pushed_at: gitea_repo.updated_at.clone(),
+            updated_at: gitea_repo.updated_at,
+            default_branch: gitea_repo.default_branch,
+            license: gitea_repo.licenses.first().map(|spdx| license_from_spdx(spdx)),
+            owner: OwnerInfo {
+                html_url: gitea_repo
+                    .owner
+                    .html_url
+                    .unwrap_or_else(|| format!("{}/{}", self.web_base(), gitea_repo.owner.login)),
+                login: gitea_repo.owner.login,
+                avatar_url: gitea_repo.owner.avatar_url,
+                avatar_data_uri: None,
+            },
+            latest_release: None,
+            contributors: Vec::new(),
+            languages: Vec::new(),
+            project_config: None,
+        })
+    }
+
+    /// Topics from the dedicated endpoint; older servers omit them from the repo response
+    async fn fetch_topics(&self, owner: &str, repo: &str, token: Option<&AccessToken>) -> Vec<String> {
+        let path = format!("repos/{}/{}/topics", owner, repo);
+        let topics = match self.api.send_checked(self.api.get(&path, token), token).await {
+            Ok(response) => response.json::<GiteaTopics>().await.ok(),
+            Err(_) => None,
+        };
+        topics.map(|t| t.topics).unwrap_or_default()
+    }
+
+    /// Fetch the commits in a range, newest first. Commits reachable from the
+    /// base are excluded with `not`, which needs Gitea 1.21+ / Forgejo 1.21+.
+    pub async fn fetch_commits(
+        &self,
+        repo_url: &str,
+        range: &CommitRange,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<CommitInfo>, AppError> {
+        let commits = self.fetch_raw_commits(repo_url, range, token).await?;
+        
+        Ok(commits
+            .into_iter()
+            .map(|c| CommitInfo {
+                sha: c.sha.chars().take(7).collect(),
+                message: c.commit.message.lines().next().unwrap_or("").to_string(),
+                author_name: c.commit.author.name,
+                author_email: c.commit.author.email,
+                date: c.commit.author.date,
+                author_login: c.author.map(|a| a.login),
+            })
+            .collect())
+    }
+
+    async fn fetch_raw_commits(
+        &self,
+        repo_url: &str,
+        range: &CommitRange,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<GiteaCommit>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/commits", owner, repo);
+        let (since, until) = range.window()?;
+        
+        // Skip per-commit stats and file lists, which are expensive on the server
+        let mut query = vec![
+            ("limit", PAGE_SIZE.to_string()),
+            ("stat", "false".to_string()),
+            ("verification", "false".to_string()),
+            ("files", "false".to_string()),
+        ];
+        query.extend(range.head.clone().map(|head| ("sha", head)));
+        query.extend(range.base.clone().map(|base| ("not", base)));
+        query.extend(since.map(|d| ("since", format_range_date(d))));
+        query.extend(until.map(|d| ("until", format_range_date(d))));
+        
+        self.api
+            .get_paginated(PAGE_SIZE, range.max_commits(), token, |page| {
+                self.api.get(&path, token).query(&query).query(&[("page", page)])
+            })
+            .await
+    }
+
+    /// Contributors ranked by their share of recent history. Gitea has no
+    /// contributors endpoint, so counts only cover the last few hundred commits.
+    pub async fn fetch_contributors(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<ContributorInfo>, AppError> {
+        let commits = self
+            .fetch_raw_commits(repo_url, &CommitRange::latest(CONTRIBUTOR_COMMIT_SCAN), token)
+            .await?;
+        
+        let mut contributors: Vec<ContributorInfo> = Vec::new();
+        for commit in commits {
+            let (login, avatar_url, html_url) = match commit.author {
+                Some(user) => {
+                    let html_url = user.html_url.unwrap_or_else(|| format!("{}/{}", self.web_base(), user.login));
+                    (user.login, user.avatar_url, html_url)
+                }
+                None => (commit.commit.author.name, String::new(), String::new()),
+            };
+            match contributors.iter_mut().find(|c| c.login == login) {
+                Some(existing) => existing.contributions += 1,
+                None => contributors.push(ContributorInfo {
+                    login,
+                    avatar_url,
+                    html_url,
+                    contributions: 1,
+                }),
+            }
+        }
+        
+        contributors.sort_by_key(|c| std::cmp::Reverse(c.contributions));
+        contributors.truncate(limit as usize);
+        Ok(contributors)
+    }
+
+    /// Byte counts per language, as Gitea's linguist port reports them
+    pub async fn fetch_languages(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<LanguageStat>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/languages", owner, repo);
+        let response = self.api.send_checked(self.api.get(&path, token), token).await?;
+        
+        let bytes: HashMap<String, u64> = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse languages: {}", e)))?;
+        Ok(language_breakdown(bytes))
+    }
+
+    /// A file from the default branch as text, or `None` when there is no such file
+    pub async fn fetch_file(
+        &self,
+        repo_url: &str,
+        file_path: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Option<String>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/raw/{}", owner, repo, file_path);
+        self.api.get_optional_text(self.api.get(&path, token), token).await
+    }
+
+    /// Fetch releases, newest first
+    pub async fn fetch_releases(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<ReleaseInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/releases", owner, repo);
+        
+        let releases: Vec<GiteaRelease> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("limit", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(releases.into_iter().map(release_info).collect())
+    }
+
+    /// The latest published, non-prerelease release, if the repository has one
+    pub async fn fetch_latest_release(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Option<ReleaseInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/releases", owner, repo);
+        // The list filters work on every Gitea/Forgejo version, unlike `/releases/latest`
+        let request = self
+            .api
+            .get(&path, token)
+            .query(&[("draft", "false"), ("pre-release", "false"), ("limit", "1")]);
+        let response = self.api.send_checked(request, token).await?;
+        
+        let releases: Vec<GiteaRelease> = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse releases: {}", e)))?;
+        Ok(releases.into_iter().next().map(release_info))
+    }
+
+    /// Fetch tags, newest first
+    pub async fn fetch_tags(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<TagInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/tags", owner, repo);
+        
+        let tags: Vec<GiteaTag> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("limit", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(tags
+            .into_iter()
+            .map(|t| TagInfo {
+                name: t.name,
+                sha: t.commit.sha,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_url_codeberg() {
+        let (owner, repo) = parse_repo_url("https://codeberg.org/forgejo/forgejo", "codeberg.org").unwrap();
+        assert_eq!(owner, "forgejo");
+        assert_eq!(repo, "forgejo");
+    }
+
+    #[test]
+    fn test_parse_repo_url_deep_link_and_suffix() {
+        let (owner, repo) = parse_repo_url("https://git.example.org/team/app.git", "git.example.org").unwrap();
+        assert_eq!((owner.as_str(), repo.as_str()), ("team", "app"));
+        
+        let (owner, repo) = parse_repo_url("git.example.org/team/app/src/branch/main/README.md", "git.example.org").unwrap();
+        assert_eq!((owner.as_str(), repo.as_str()), ("team", "app"));
+    }
+
+    #[test]
+    fn test_parse_repo_url_invalid() {
+        assert!(parse_repo_url("https://codeberg.org/forgejo", "codeberg.org").is_err());
+        assert!(parse_repo_url("https://github.com/owner/repo", "codeberg.org").is_err());
+    }
+
+    #[test]
+    fn test_license_from_spdx() {
+        let license = license_from_spdx("GPL-3.0-or-later");
+        assert_eq!(license.key, "gpl-3.0-or-later");
+        assert_eq!(license.spdx_id.as_deref(), Some("GPL-3.0-or-later"));
+    }
+
+    #[test]
+    fn test_repo_response_without_optional_fields() {
+        let json = r#"{
+            "name": "app", "full_name": "team/app", "description": "", "html_url": "https://git.example.org/team/app",
+            "stars_count": 3, "forks_count": 1, "watchers_count": 2, "open_issues_count": 0, "language": "",
+            "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-06-01T00:00:00Z", "default_branch": "main",
+            "owner": {"login": "team", "avatar_url": "https://git.example.org/avatars/1"}
+        }"#;
+        let repo: GiteaRepoResponse = serde_json::from_str(json).unwrap();
+        assert!(repo.topics.is_none());
+        assert!(repo.licenses.is_empty());
+        assert!(repo.owner.html_url.is_none());
+    }
+}
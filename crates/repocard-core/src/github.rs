@@ -0,0 +1,585 @@
+// RepoCard Studio - GitHub API Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::provider::{format_range_date, AccessToken, ApiClient, ProviderKind, ServerConfig, MAX_RANGE_COMMITS};
+use crate::repo_ref::RepoRef;
+use crate::{CommitInfo, CommitRange, RateLimitStatus, RepoMetadata, LicenseInfo, OwnerInfo, TokenInfo};
+use crate::{language_breakdown, ContributorInfo, LanguageStat, ReleaseAsset, ReleaseInfo, TagInfo};
+use reqwest::header::ACCEPT;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Items per page; the most GitHub allows
+const PAGE_SIZE: usize = 100;
+
+/// Long-lived GitHub API client.
+///
+/// Cheap to clone; clones share the connection pool and rate limit state.
+#[derive(Debug, Clone)]
+pub struct GitHubClient {
+    api: ApiClient,
+    config: ServerConfig,
+}
+
+impl GitHubClient {
+    pub fn new(config: ServerConfig) -> Result<Self, AppError> {
+        let api = ApiClient::new(ProviderKind::GitHub, &config, "application/vnd.github+json")?;
+        Ok(Self { api, config })
+    }
+    
+    pub fn config(&self) -> &ServerConfig {
+        &self.config
+    }
+    
+    /// A client sharing this one's connections that revalidates cached responses
+    pub fn refreshing(&self) -> Self {
+        Self {
+            api: self.api.refreshing(),
+            ..self.clone()
+        }
+    }
+    
+    /// Rate limit state reported by the most recent response, if any
+    pub fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        self.api.last_rate_limit()
+    }
+}
+
+/// Parse owner and repo from any GitHub URL shape on the given host
+fn parse_repo_url(url: &str, host: &str) -> Result<(String, String), AppError> {
+    let invalid = || AppError::parse(format!("Invalid GitHub URL or repo format: {}", url.trim()));
+    let repo_ref = RepoRef::parse_with_default_host(url, host).map_err(|_| invalid())?;
+    
+    // GitHub has no nested namespaces
+    if repo_ref.host != host || repo_ref.owner.contains('/') {
+        return Err(invalid());
+    }
+    Ok((repo_ref.owner, repo_ref.repo))
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepoResponse {
+    name: String,
+    full_name: String,
+    description: Option<String>,
+    html_url: String,
+    stargazers_count: u32,
+    forks_count: u32,
+    watchers_count: u32,
+    open_issues_count: u32,
+    language: Option<String>,
+    topics: Option<Vec<String>>,
+    created_at: String,
+    updated_at: String,
+    pushed_at: String,
+    default_branch: String,
+    license: Option<GitHubLicense>,
+    owner: GitHubOwner,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubLicense {
+    key: String,
+    name: String,
+    spdx_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubOwner {
+    login: String,
+    avatar_url: String,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubCommit {
+    sha: String,
+    commit: GitHubCommitDetails,
+    /// GitHub account matched to the commit email, if any
+    author: Option<GitHubUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubCommitDetails {
+    message: String,
+    author: GitHubCommitAuthor,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubCommitAuthor {
+    name: String,
+    email: String,
+    date: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubCompare {
+    total_commits: usize,
+    commits: Vec<GitHubCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+    html_url: String,
+    published_at: Option<String>,
+    draft: bool,
+    prerelease: bool,
+    #[serde(default)]
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    size: u64,
+    download_count: u64,
+    browser_download_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubTag {
+    name: String,
+    commit: GitHubTagCommit,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubTagCommit {
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubContributor {
+    login: String,
+    avatar_url: String,
+    html_url: String,
+    contributions: u32,
+    #[serde(rename = "type")]
+    account_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    login: String,
+}
+
+impl GitHubClient {
+    /// Fetch repository metadata from GitHub API
+    pub async fn fetch_repository_metadata(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<RepoMetadata, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}", owner, repo);
+        let response = self.api.send_checked(self.api.get(&path, token), token).await?;
+        
+        let github_repo: GitHubRepoResponse = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse GitHub response: {}", e)))?;
+        
+        Ok(RepoMetadata {
+            name: github_repo.name,
+            full_name: github_repo.full_name,
+            description: github_repo.description,
+            html_url: github_repo.html_url,
+            stargazers_count: github_repo.stargazers_count,
+            forks_count: github_repo.forks_count,
+            watchers_count: github_repo.watchers_count,
+            open_issues_count: github_repo.open_issues_count,
+            language: github_repo.language,
+            topics: github_repo.topics.unwrap_or_default(),
+            created_at: github_repo.created_at,
+            updated_at: github_repo.updated_at,
+            pushed_at: github_repo.pushed_at,
+            default_branch: github_repo.default_branch,
+            license: github_repo.license.map(|l| LicenseInfo {
+                key: l.key,
+                name: l.name,
+                spdx_id: l.spdx_id,
+            }),
+            owner: OwnerInfo {
+                login: github_repo.owner.login,
+                avatar_url: github_repo.owner.avatar_url,
+                avatar_data_uri: None,
+                html_url: github_repo.owner.html_url,
+            },
+            latest_release: None,
+            contributors: Vec::new(),
+            languages: Vec::new(),
+            project_config: None,
+        })
+    }
+
+    /// Fetch the commits in a range from GitHub, newest first
+    pub async fn fetch_commits(
+        &self,
+        repo_url: &str,
+        range: &CommitRange,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<CommitInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        
+        let commits = match &range.base {
+            Some(base) => self.fetch_compare(&owner, &repo, base, range, token).await?,
+            None => {
+                let (since, until) = range.window()?;
+                let path = format!("repos/{}/{}/commits", owner, repo);
+                let mut query = vec![("per_page", PAGE_SIZE.to_string())];
+                query.extend(range.head.clone().map(|head| ("sha", head)));
+                query.extend(since.map(|d| ("since", format_range_date(d))));
+                query.extend(until.map(|d| ("until", format_range_date(d))));
+                
+                self.api
+                    .get_paginated::<GitHubCommit>(PAGE_SIZE, range.max_commits(), token, |page| {
+                        self.api.get(&path, token).query(&query).query(&[("page", page)])
+                    })
+                    .await?
+            }
+        };
+        
+        Ok(commits.into_iter().map(commit_info).collect())
+    }
+
+    /// Commits in `base...head` via the compare endpoint. It lists them oldest first,
+    /// so pages are read from the last one back until the range has enough commits.
+    async fn fetch_compare(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        range: &CommitRange,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<GitHubCommit>, AppError> {
+        let head = range.head.as_deref().unwrap_or("HEAD");
+        let path = format!("repos/{}/{}/compare/{}...{}", owner, repo, encode_ref(base), encode_ref(head));
+        let path = &path;
+        let fetch_page = |page: usize| async move {
+            let request = self.api.get(path, token).query(&[("per_page", PAGE_SIZE), ("page", page)]);
+            let response = self.api.send_checked(request, token).await?;
+            response
+                .json::<GitHubCompare>()
+                .await
+                .map_err(|e| AppError::parse(format!("Failed to parse commits: {}", e)))
+        };
+        
+        // The first page says how many pages there are; keep it in case the range reaches back to it
+        let first = fetch_page(1).await?;
+        let pages = first.total_commits.div_ceil(PAGE_SIZE).max(1);
+        let mut first = Some(first);
+        let mut commits: Vec<GitHubCommit> = Vec::new();
+        let mut scanned = 0;
+        for page in (1..=pages).rev() {
+            let kept = if page == 1 { first.take() } else { None };
+            let compare = match kept {
+                Some(compare) => compare,
+                None => fetch_page(page).await?,
+            };
+            scanned += compare.commits.len();
+            commits.extend(compare.commits.into_iter().rev().filter(|c| range.includes_date(&c.commit.author.date)));
+            if commits.len() >= range.max_commits() || scanned >= MAX_RANGE_COMMITS {
+                break;
+            }
+        }
+        
+        commits.truncate(range.max_commits());
+        Ok(commits)
+    }
+
+    /// Fetch releases, newest first
+    pub async fn fetch_releases(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<ReleaseInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/releases", owner, repo);
+        
+        let releases: Vec<GitHubRelease> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("per_page", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(releases.into_iter().map(release_info).collect())
+    }
+
+    /// The latest published, non-prerelease release, if the repository has one
+    pub async fn fetch_latest_release(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Option<ReleaseInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/releases/latest", owner, repo);
+        let response = self.api.send_with_retry(self.api.get(&path, token), token).await?;
+        
+        // GitHub answers 404 when there is no release yet
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = self.api.ensure_success(response, token).await?;
+        
+        let release: GitHubRelease = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse release: {}", e)))?;
+        Ok(Some(release_info(release)))
+    }
+
+    /// Fetch tags in the order GitHub lists them
+    pub async fn fetch_tags(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<TagInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/tags", owner, repo);
+        
+        let tags: Vec<GitHubTag> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("per_page", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(tags
+            .into_iter()
+            .map(|t| TagInfo {
+                name: t.name,
+                sha: t.commit.sha,
+            })
+            .collect())
+    }
+
+    /// Top contributors by commit count on the default branch, leaving out bots
+    pub async fn fetch_contributors(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<ContributorInfo>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/contributors", owner, repo);
+        
+        let contributors: Vec<GitHubContributor> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("per_page", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(contributors
+            .into_iter()
+            .filter(|c| c.account_type != "Bot")
+            .map(|c| ContributorInfo {
+                login: c.login,
+                avatar_url: c.avatar_url,
+                html_url: c.html_url,
+                contributions: c.contributions,
+            })
+            .collect())
+    }
+
+    /// Byte counts per language as GitHub's linguist reports them
+    pub async fn fetch_languages(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<LanguageStat>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/languages", owner, repo);
+        let response = self.api.send_checked(self.api.get(&path, token), token).await?;
+        
+        let bytes: HashMap<String, u64> = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse languages: {}", e)))?;
+        Ok(language_breakdown(bytes))
+    }
+
+    /// A file from the default branch as text, or `None` when there is no such file
+    pub async fn fetch_file(
+        &self,
+        repo_url: &str,
+        file_path: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Option<String>, AppError> {
+        let (owner, repo) = parse_repo_url(repo_url, &self.config.host)?;
+        let path = format!("repos/{}/{}/contents/{}", owner, repo, file_path);
+        let request = self.api.get(&path, token).header(ACCEPT, "application/vnd.github.raw+json");
+        self.api.get_optional_text(request, token).await
+    }
+
+    /// Check a token against the API and report the account and scopes it grants
+    pub async fn validate_token(&self, token: &AccessToken) -> Result<TokenInfo, AppError> {
+        let response = self.api.send_with_retry(self.api.get("user", Some(token)), Some(token)).await?;
+        
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(AppError::unauthorized("GitHub token is invalid or expired"));
+        }
+        let response = self.api.ensure_success(response, Some(token)).await?;
+        
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let scopes = header("x-oauth-scopes").map(|s| parse_scopes(&s)).unwrap_or_default();
+        let expires_at = header("github-authentication-token-expiration");
+        
+        let user: GitHubUser = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(token.redact(&format!("Failed to parse GitHub response: {}", e))))?;
+        
+        Ok(TokenInfo {
+            login: user.login,
+            can_read_private_repos: scopes.iter().any(|s| s == "repo"),
+            scopes,
+            expires_at,
+        })
+    }
+}
+
+/// A branch, tag or SHA for a URL path: each `/`-separated segment is percent-encoded
+fn encode_ref(name: &str) -> String {
+    name.split('/')
+        .map(|segment| {
+            segment
+                .bytes()
+                .map(|b| match b {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+                    _ => format!("%{:02X}", b),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn commit_info(c: GitHubCommit) -> CommitInfo {
+    CommitInfo {
+        sha: c.sha.chars().take(7).collect(),
+        message: c.commit.message.lines().next().unwrap_or("").to_string(),
+        author_name: c.commit.author.name,
+        author_email: c.commit.author.email,
+        date: c.commit.author.date,
+        author_login: c.author.map(|a| a.login),
+    }
+}
+
+fn release_info(r: GitHubRelease) -> ReleaseInfo {
+    ReleaseInfo {
+        tag_name: r.tag_name,
+        name: r.name.filter(|n| !n.trim().is_empty()),
+        body: r.body.filter(|b| !b.trim().is_empty()),
+        html_url: r.html_url,
+        published_at: r.published_at,
+        draft: r.draft,
+        prerelease: r.prerelease,
+        assets: r
+            .assets
+            .into_iter()
+            .map(|a| ReleaseAsset {
+                name: a.name,
+                size: a.size,
+                download_count: a.download_count,
+                download_url: a.browser_download_url,
+            })
+            .collect(),
+    }
+}
+
+/// Split the comma separated `X-OAuth-Scopes` header
+fn parse_scopes(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_url_full() {
+        let result = parse_repo_url("https://github.com/microsoft/vscode", "github.com");
+        assert!(result.is_ok());
+        let (owner, repo) = result.unwrap();
+        assert_eq!(owner, "microsoft");
+        assert_eq!(repo, "vscode");
+    }
+
+    #[test]
+    fn test_parse_repo_url_short() {
+        let result = parse_repo_url("facebook/react", "github.com");
+        assert!(result.is_ok());
+        let (owner, repo) = result.unwrap();
+        assert_eq!(owner, "facebook");
+        assert_eq!(repo, "react");
+    }
+
+    #[test]
+    fn test_parse_repo_url_invalid() {
+        let result = parse_repo_url("invalid-url", "github.com");
+        assert_eq!(result.unwrap_err().kind(), "parse");
+    }
+
+    #[test]
+    fn test_parse_repo_url_enterprise_host() {
+        let (owner, repo) = parse_repo_url("https://ghe.example.com/platform/api-gateway", "ghe.example.com").unwrap();
+        assert_eq!(owner, "platform");
+        assert_eq!(repo, "api-gateway");
+        assert!(parse_repo_url("https://github.com/platform/api-gateway/extra", "ghe.example.com").is_err());
+    }
+
+    #[test]
+    fn test_client_joins_api_paths() {
+        let config = ServerConfig::for_server(ProviderKind::GitHub, "ghe.example.com").unwrap();
+        let client = GitHubClient::new(config).unwrap();
+        assert_eq!(client.api.api_url("repos/a/b"), "https://ghe.example.com/api/v3/repos/a/b");
+        assert!(client.last_rate_limit().is_none());
+    }
+
+    #[test]
+    fn test_encodes_ref_segments() {
+        assert_eq!(encode_ref("release/1.0"), "release/1.0");
+        assert_eq!(encode_ref("v1.2.0"), "v1.2.0");
+        assert_eq!(encode_ref("fix#12?draft"), "fix%2312%3Fdraft");
+        assert_eq!(encode_ref("100%/ünï"), "100%25/%C3%BCn%C3%AF");
+    }
+
+    #[test]
+    fn test_parse_scopes() {
+        assert_eq!(parse_scopes("repo, read:org"), vec!["repo", "read:org"]);
+        assert!(parse_scopes("").is_empty());
+    }
+
+    #[test]
+    fn test_release_maps_assets() {
+        let json = r#"{
+            "tag_name": "v1.3.0", "name": "", "body": "Notes", "html_url": "https://github.com/o/r/releases/tag/v1.3.0",
+            "published_at": "2024-05-01T12:00:00Z", "draft": false, "prerelease": false,
+            "assets": [{"name": "app.dmg", "size": 2048, "download_count": 17,
+                        "browser_download_url": "https://github.com/o/r/releases/download/v1.3.0/app.dmg"}]
+        }"#;
+        let release = release_info(serde_json::from_str(json).unwrap());
+        assert_eq!(release.tag_name, "v1.3.0");
+        assert!(release.name.is_none());
+        assert_eq!(release.assets[0].download_count, 17);
+    }
+}
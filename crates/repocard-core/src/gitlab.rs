@@ -0,0 +1,506 @@
+// RepoCard Studio - GitLab API Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::provider::{format_range_date, AccessToken, ApiClient, ProviderKind, ServerConfig};
+use crate::repo_ref::RepoRef;
+use crate::{CommitInfo, CommitRange, LicenseInfo, OwnerInfo, RateLimitStatus, RepoMetadata};
+use crate::{language_shares, ContributorInfo, LanguageStat, ReleaseAsset, ReleaseInfo, TagInfo};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Items per page; the most GitLab allows
+const PAGE_SIZE: usize = 100;
+/// Releases inspected when looking for the latest one that is not upcoming
+const LATEST_RELEASE_SCAN: usize = 10;
+
+/// Long-lived GitLab API client for gitlab.com or a self-managed instance.
+///
+/// Cheap to clone; clones share the connection pool and rate limit state.
+#[derive(Debug, Clone)]
+pub struct GitLabClient {
+    api: ApiClient,
+    config: ServerConfig,
+}
+
+/// Parse the full project path (`group/subgroup/project`) from a GitLab URL on the given host
+fn parse_project_path(url: &str, host: &str) -> Result<String, AppError> {
+    let invalid = || AppError::parse(format!("Invalid GitLab URL or project format: {}", url.trim()));
+    let repo_ref = RepoRef::parse(url).map_err(|_| invalid())?;
+    
+    if repo_ref.host != host {
+        return Err(invalid());
+    }
+    Ok(repo_ref.full_name())
+}
+
+/// Project paths are used as a single URL-encoded id in the API
+fn encode_project_path(path: &str) -> String {
+    path.replace('/', "%2F")
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabProject {
+    id: u64,
+    path: String,
+    path_with_namespace: String,
+    description: Option<String>,
+    web_url: String,
+    #[serde(default)]
+    star_count: u32,
+    #[serde(default)]
+    forks_count: u32,
+    /// Absent when the issue tracker is disabled
+    open_issues_count: Option<u32>,
+    #[serde(default)]
+    topics: Vec<String>,
+    /// Older GitLab versions only provide `tag_list`
+    #[serde(default)]
+    tag_list: Vec<String>,
+    created_at: String,
+    last_activity_at: String,
+    updated_at: Option<String>,
+    default_branch: Option<String>,
+    license: Option<GitLabLicense>,
+    namespace: GitLabNamespace,
+    /// Only present for projects in a user namespace
+    owner: Option<GitLabUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabLicense {
+    key: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabNamespace {
+    full_path: String,
+    avatar_url: Option<String>,
+    web_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabUser {
+    username: String,
+    avatar_url: Option<String>,
+    web_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabCommit {
+    id: String,
+    title: String,
+    author_name: String,
+    author_email: String,
+    authored_date: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabRelease {
+    tag_name: String,
+    name: Option<String>,
+    description: Option<String>,
+    released_at: Option<String>,
+    #[serde(default)]
+    upcoming_release: bool,
+    #[serde(rename = "_links")]
+    links: GitLabReleaseLinks,
+    assets: GitLabReleaseAssets,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabReleaseLinks {
+    #[serde(rename = "self")]
+    web: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabReleaseAssets {
+    #[serde(default)]
+    links: Vec<GitLabAssetLink>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabAssetLink {
+    name: String,
+    url: String,
+    /// Stable download URL; only set for permanent links
+    direct_asset_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabContributor {
+    name: String,
+    commits: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabTag {
+    name: String,
+    commit: GitLabTagCommit,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabTagCommit {
+    id: String,
+}
+
+/// GitLab neither counts downloads nor reports sizes for release links
+fn release_info(r: GitLabRelease) -> ReleaseInfo {
+    ReleaseInfo {
+        tag_name: r.tag_name,
+        name: r.name.filter(|n| !n.trim().is_empty()),
+        body: r.description.filter(|d| !d.trim().is_empty()),
+        html_url: r.links.web,
+        published_at: r.released_at,
+        draft: false,
+        // Releases dated in the future are announced but not yet shipped
+        prerelease: r.upcoming_release,
+        assets: r
+            .assets
+            .links
+            .into_iter()
+            .map(|l| ReleaseAsset {
+                name: l.name,
+                size: 0,
+                download_count: 0,
+                download_url: l.direct_asset_url.unwrap_or(l.url),
+            })
+            .collect(),
+    }
+}
+
+impl GitLabClient {
+    pub fn new(config: ServerConfig) -> Result<Self, AppError> {
+        let api = ApiClient::new(ProviderKind::GitLab, &config, "application/json")?;
+        Ok(Self { api, config })
+    }
+
+    pub fn config(&self) -> &ServerConfig {
+        &self.config
+    }
+
+    /// A client sharing this one's connections that revalidates cached responses
+    pub fn refreshing(&self) -> Self {
+        Self {
+            api: self.api.refreshing(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether repository URLs on this host belong to this instance
+    pub fn handles_host(&self, host: &str) -> bool {
+        host == self.config.host
+    }
+
+    /// Rate limit state reported by the most recent response, if any
+    pub fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        self.api.last_rate_limit()
+    }
+
+    /// Web root of the instance, used to absolutise relative avatar paths
+    fn web_base(&self) -> &str {
+        self.config.api_base.trim_end_matches("/api/v4")
+    }
+
+    fn absolute_url(&self, url: Option<String>) -> String {
+        match url {
+            Some(url) if url.starts_with('/') => format!("{}{}", self.web_base(), url),
+            Some(url) => url,
+            None => String::new(),
+        }
+    }
+
+    /// Fetch project metadata from GitLab and map it onto `RepoMetadata`
+    pub async fn fetch_repository_metadata(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<RepoMetadata, AppError> {
+        let project_path = parse_project_path(repo_url, &self.config.host)?;
+        let path = format!("projects/{}?license=true", encode_project_path(&project_path));
+        let response = self.api.send_checked(self.api.get(&path, token), token).await?;
+        
+        let project: GitLabProject = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse GitLab response: {}", e)))?;
+        
+        // GitLab reports language percentages separately; a failure here is not fatal
+        let languages = self.fetch_languages(project.id, token).await.unwrap_or_default();
+        
+        let owner = match project.owner {
+            Some(user) => OwnerInfo {
+                login: user.username,
+                avatar_url: self.absolute_url(user.avatar_url),
+                avatar_data_uri: None,
+                html_url: user.web_url,
+            },
+            None => OwnerInfo {
+                html_url: project
+                    .namespace
+                    .web_url
+                    .unwrap_or_else(|| format!("{}/{}", self.web_base(), project.namespace.full_path)),
+                avatar_url: self.absolute_url(project.namespace.avatar_url),
+                avatar_data_uri: None,
+                login: project.namespace.full_path,
+            },
+        };
+        
+        let topics = if project.topics.is_empty() { project.tag_list } else { project.topics };
+        
+        Ok(RepoMetadata {
+            name: project.path,
+            full_name: project.path_with_namespace,
+            description: project.description.filter(|d| !d.trim().is_empty()),
+            html_url: project.web_url,
+            stargazers_count: project.star_count,
+            forks_count: project.forks_count,
+            // GitLab has no separate watcher count; stars are the closest equivalent
+            watchers_count: project.star_count,
+            open_issues_count: project.open_issues_count.unwrap_or(0),
+            language: languages.first().map(|l| l.name.clone()),
+            topics,
+            created_at: project.created_at,
+            updated_at: project.updated_at.unwrap_or_else(|| project.last_activity_at.clone()),
+            pushed_at: project.last_activity_at,
+            default_branch: project.default_branch.unwrap_or_else(|| "main".to_string()),
+            license: project.license.map(|l| LicenseInfo {
+                key: l.key,
+                name: l.name,
+                spdx_id: None,
+            }),
+            owner,
+            latest_release: None,
+            contributors: Vec::new(),
+            languages,
+            project_config: None,
+        })
+    }
+
+    /// Language shares of the repository. GitLab reports percentages, not bytes.
+    async fn fetch_languages(&self, project_id: u64, token: Option<&AccessToken>) -> Result<Vec<LanguageStat>, AppError> {
+        let path = format!("projects/{}/languages", project_id);
+        let response = self.api.send_checked(self.api.get(&path, token), token).await?;
+        
+        let percentages: HashMap<String, f64> = response
+            .json()
+            .await
+            .map_err(|e| AppError::parse(format!("Failed to parse languages: {}", e)))?;
+        Ok(language_shares(percentages))
+    }
+
+    /// Fetch the commits in a range, newest first. GitLab takes `base..head`
+    /// revision ranges directly in `ref_name`.
+    pub async fn fetch_commits(
+        &self,
+        repo_url: &str,
+        range: &CommitRange,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<CommitInfo>, AppError> {
+        let project_path = parse_project_path(repo_url, &self.config.host)?;
+        let path = format!("projects/{}/repository/commits", encode_project_path(&project_path));
+        let (since, until) = range.window()?;
+        
+        let mut query = vec![("per_page", PAGE_SIZE.to_string())];
+        let ref_name = match (&range.base, &range.head) {
+            (Some(base), head) => Some(format!("{}..{}", base, head.as_deref().unwrap_or("HEAD"))),
+            (None, head) => head.clone(),
+        };
+        query.extend(ref_name.map(|r| ("ref_name", r)));
+        query.extend(since.map(|d| ("since", format_range_date(d))));
+        query.extend(until.map(|d| ("until", format_range_date(d))));
+        
+        let commits: Vec<GitLabCommit> = self
+            .api
+            .get_paginated(PAGE_SIZE, range.max_commits(), token, |page| {
+                self.api.get(&path, token).query(&query).query(&[("page", page)])
+            })
+            .await?;
+        
+        Ok(commits
+            .into_iter()
+            .map(|c| CommitInfo {
+                sha: c.id.chars().take(7).collect(),
+                message: c.title,
+                author_name: c.author_name,
+                author_email: c.author_email,
+                date: c.authored_date,
+                author_login: None,
+            })
+            .collect())
+    }
+
+    /// A file from the default branch as text, or `None` when there is no such file
+    pub async fn fetch_file(
+        &self,
+        repo_url: &str,
+        file_path: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Option<String>, AppError> {
+        let project_path = parse_project_path(repo_url, &self.config.host)?;
+        let path = format!(
+            "projects/{}/repository/files/{}/raw",
+            encode_project_path(&project_path),
+            encode_project_path(file_path)
+        );
+        self.api.get_optional_text(self.api.get(&path, token), token).await
+    }
+
+    /// Fetch releases, newest first
+    pub async fn fetch_releases(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<ReleaseInfo>, AppError> {
+        let project_path = parse_project_path(repo_url, &self.config.host)?;
+        let path = format!("projects/{}/releases", encode_project_path(&project_path));
+        
+        let releases: Vec<GitLabRelease> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("per_page", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(releases.into_iter().map(release_info).collect())
+    }
+
+    /// The most recently released version, skipping upcoming releases
+    pub async fn fetch_latest_release(
+        &self,
+        repo_url: &str,
+        token: Option<&AccessToken>,
+    ) -> Result<Option<ReleaseInfo>, AppError> {
+        let releases = self.fetch_releases(repo_url, LATEST_RELEASE_SCAN as u32, token).await?;
+        Ok(releases.into_iter().find(|r| !r.prerelease))
+    }
+
+    /// Top contributors by commit count. GitLab only reports commit author
+    /// names here, so there are no avatars or profile links.
+    pub async fn fetch_contributors(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<ContributorInfo>, AppError> {
+        let project_path = parse_project_path(repo_url, &self.config.host)?;
+        let path = format!("projects/{}/repository/contributors", encode_project_path(&project_path));
+        
+        let contributors: Vec<GitLabContributor> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[
+                    ("order_by", "commits".to_string()),
+                    ("sort", "desc".to_string()),
+                    ("per_page", PAGE_SIZE.to_string()),
+                    ("page", page.to_string()),
+                ])
+            })
+            .await?;
+        
+        Ok(contributors
+            .into_iter()
+            .map(|c| ContributorInfo {
+                login: c.name,
+                avatar_url: String::new(),
+                html_url: String::new(),
+                contributions: c.commits,
+            })
+            .collect())
+    }
+
+    /// Fetch tags, most recently updated first
+    pub async fn fetch_tags(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<&AccessToken>,
+    ) -> Result<Vec<TagInfo>, AppError> {
+        let project_path = parse_project_path(repo_url, &self.config.host)?;
+        let path = format!("projects/{}/repository/tags", encode_project_path(&project_path));
+        
+        let tags: Vec<GitLabTag> = self
+            .api
+            .get_paginated(PAGE_SIZE, limit.max(1) as usize, token, |page| {
+                self.api.get(&path, token).query(&[("per_page", PAGE_SIZE as u32), ("page", page)])
+            })
+            .await?;
+        
+        Ok(tags
+            .into_iter()
+            .map(|t| TagInfo {
+                name: t.name,
+                sha: t.commit.id,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project_path_nested_groups() {
+        let path = parse_project_path("https://gitlab.com/gitlab-org/ci-cd/runner-tools/base-images", "gitlab.com");
+        assert_eq!(path.unwrap(), "gitlab-org/ci-cd/runner-tools/base-images");
+    }
+
+    #[test]
+    fn test_parse_project_path_strips_deep_links() {
+        assert_eq!(
+            parse_project_path("https://gitlab.com/group/project/-/tree/main/src", "gitlab.com").unwrap(),
+            "group/project"
+        );
+        assert_eq!(
+            parse_project_path("gitlab.com/group/project.git", "gitlab.com").unwrap(),
+            "group/project"
+        );
+    }
+
+    #[test]
+    fn test_parse_project_path_invalid() {
+        assert!(parse_project_path("https://gitlab.com/group", "gitlab.com").is_err());
+        assert!(parse_project_path("https://github.com/owner/repo", "gitlab.com").is_err());
+        assert!(parse_project_path("group/project", "gitlab.com").is_err());
+    }
+
+    #[test]
+    fn test_encode_project_path() {
+        assert_eq!(encode_project_path("group/sub/project"), "group%2Fsub%2Fproject");
+    }
+
+    #[test]
+    fn test_project_response_deserializes() {
+        let json = r#"{
+            "id": 42, "path": "project", "path_with_namespace": "group/sub/project",
+            "description": "", "web_url": "https://gitlab.com/group/sub/project",
+            "star_count": 7, "forks_count": 2, "tag_list": ["cli"],
+            "created_at": "2024-01-01T00:00:00.000Z", "last_activity_at": "2024-06-01T00:00:00.000Z",
+            "default_branch": "main", "license": null,
+            "namespace": {"full_path": "group/sub", "avatar_url": null, "web_url": "https://gitlab.com/groups/group/sub"}
+        }"#;
+        let project: GitLabProject = serde_json::from_str(json).unwrap();
+        assert_eq!(project.path_with_namespace, "group/sub/project");
+        assert_eq!(project.tag_list, vec!["cli"]);
+        assert!(project.owner.is_none());
+        assert!(project.open_issues_count.is_none());
+    }
+
+    #[test]
+    fn test_release_prefers_direct_asset_url() {
+        let json = r#"{
+            "tag_name": "v2.0.0", "name": "v2.0.0", "description": "", "released_at": "2024-05-01T12:00:00.000Z",
+            "_links": {"self": "https://gitlab.com/group/project/-/releases/v2.0.0"},
+            "assets": {"count": 1, "links": [{"name": "linux", "url": "https://example.com/tmp/linux.tar.gz",
+                "direct_asset_url": "https://gitlab.com/group/project/-/releases/v2.0.0/downloads/linux.tar.gz"}]}
+        }"#;
+        let release = release_info(serde_json::from_str(json).unwrap());
+        assert!(release.body.is_none());
+        assert!(!release.prerelease);
+        assert!(release.assets[0].download_url.ends_with("/downloads/linux.tar.gz"));
+    }
+}
@@ -0,0 +1,421 @@
+// RepoCard Studio - Languages Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::LanguageStat;
+
+/// Colour for languages linguist leaves uncoloured, and for the "Other" bucket
+pub const DEFAULT_LANGUAGE_COLOR: &str = "#6e7681";
+
+/// Language colours from GitHub linguist's `languages.yml`
+const LANGUAGE_COLORS: &[(&str, &str)] = &[
+    ("1C Enterprise", "#814CCC"),
+    ("ABAP", "#E8274B"),
+    ("ActionScript", "#882B0F"),
+    ("Ada", "#02f88c"),
+    ("Agda", "#315665"),
+    ("AGS Script", "#B9D9FF"),
+    ("AL", "#3AA2B5"),
+    ("Alloy", "#64C800"),
+    ("AMPL", "#E6EFBB"),
+    ("AngelScript", "#C7D7DC"),
+    ("ANTLR", "#9DC3FF"),
+    ("ApacheConf", "#d12127"),
+    ("Apex", "#1797c0"),
+    ("APL", "#5A8164"),
+    ("AppleScript", "#101F1F"),
+    ("Arc", "#aa2afe"),
+    ("AsciiDoc", "#73a0c5"),
+    ("AspectJ", "#a957b0"),
+    ("Assembly", "#6E4C13"),
+    ("Astro", "#ff5a03"),
+    ("ATS", "#1ac620"),
+    ("AutoHotkey", "#6594b9"),
+    ("AutoIt", "#1C3552"),
+    ("Awk", "#c30e9b"),
+    ("Ballerina", "#FF5000"),
+    ("Batchfile", "#C1F12E"),
+    ("Beef", "#a52f4e"),
+    ("Bicep", "#519aba"),
+    ("BitBake", "#00bce4"),
+    ("Blade", "#f7523f"),
+    ("BlitzBasic", "#00FFAE"),
+    ("Boo", "#d4bec1"),
+    ("Brainfuck", "#2F2530"),
+    ("C", "#555555"),
+    ("C#", "#178600"),
+    ("C++", "#f34b7d"),
+    ("Cairo", "#ff4a48"),
+    ("Cap'n Proto", "#c42727"),
+    ("Ceylon", "#dfa535"),
+    ("Chapel", "#8dc63f"),
+    ("ChucK", "#3f8000"),
+    ("Cirru", "#ccccff"),
+    ("Clarion", "#db901e"),
+    ("Clojure", "#db5855"),
+    ("CMake", "#DA3434"),
+    ("CodeQL", "#140f46"),
+    ("CoffeeScript", "#244776"),
+    ("ColdFusion", "#ed2cd6"),
+    ("Common Lisp", "#3fb68b"),
+    ("Common Workflow Language", "#B5314C"),
+    ("Component Pascal", "#B0CE4E"),
+    ("Coq", "#d0b68c"),
+    ("Crystal", "#000100"),
+    ("CSS", "#563d7c"),
+    ("Cuda", "#3A4E3A"),
+    ("Cython", "#fedf5b"),
+    ("D", "#ba595e"),
+    ("Dart", "#00B4AB"),
+    ("DataWeave", "#003a52"),
+    ("Dhall", "#dfafff"),
+    ("DM", "#447265"),
+    ("Dockerfile", "#384d54"),
+    ("Dylan", "#6c616e"),
+    ("E", "#ccce35"),
+    ("Earthly", "#2af0ff"),
+    ("eC", "#913960"),
+    ("ECL", "#8a1267"),
+    ("Eiffel", "#4d6977"),
+    ("Elixir", "#6e4a7e"),
+    ("Elm", "#60B5CC"),
+    ("Emacs Lisp", "#c065db"),
+    ("EmberScript", "#FFF4F3"),
+    ("Erlang", "#B83998"),
+    ("F#", "#b845fc"),
+    ("F*", "#572e30"),
+    ("Factor", "#636746"),
+    ("Fancy", "#7b9db4"),
+    ("Fantom", "#14253c"),
+    ("Fennel", "#fff3d7"),
+    ("Fortran", "#4d41b1"),
+    ("FreeMarker", "#0050b2"),
+    ("Frege", "#00cafe"),
+    ("Futhark", "#5f021f"),
+    ("G-code", "#D08CF2"),
+    ("GAML", "#FFC766"),
+    ("GDScript", "#355570"),
+    ("Genie", "#fb855d"),
+    ("Gherkin", "#5B2063"),
+    ("Gleam", "#ffaff3"),
+    ("GLSL", "#5686a5"),
+    ("Go", "#00ADD8"),
+    ("Golo", "#88562A"),
+    ("Gosu", "#82937f"),
+    ("Grammatical Framework", "#ff0000"),
+    ("GraphQL", "#e10098"),
+    ("Groovy", "#4298b8"),
+    ("Hack", "#878787"),
+    ("Haml", "#ece2a9"),
+    ("Handlebars", "#f7931e"),
+    ("Harbour", "#0e60e3"),
+    ("Haskell", "#5e5086"),
+    ("Haxe", "#df7900"),
+    ("HCL", "#844FBA"),
+    ("HiveQL", "#dce200"),
+    ("HLSL", "#aace60"),
+    ("HolyC", "#ffefaf"),
+    ("HTML", "#e34c26"),
+    ("Hy", "#7790B2"),
+    ("IDL", "#a3522f"),
+    ("Idris", "#b30000"),
+    ("Io", "#a9188d"),
+    ("Ioke", "#078193"),
+    ("Isabelle", "#FEFE00"),
+    ("J", "#9EEDFF"),
+    ("Janet", "#0886a5"),
+    ("Java", "#b07219"),
+    ("JavaScript", "#f1e05a"),
+    ("Jinja", "#a52a22"),
+    ("Jolie", "#843179"),
+    ("JSON", "#292929"),
+    ("Jsonnet", "#0064bd"),
+    ("Julia", "#a270ba"),
+    ("Jupyter Notebook", "#DA5B0B"),
+    ("Just", "#384d54"),
+    ("Kotlin", "#A97BFF"),
+    ("KRL", "#28430A"),
+    ("LabVIEW", "#fede06"),
+    ("Lasso", "#999999"),
+    ("Latte", "#f2a542"),
+    ("Less", "#1d365d"),
+    ("Lex", "#DBCA00"),
+    ("LFE", "#4C3023"),
+    ("LiveScript", "#499886"),
+    ("LLVM", "#185619"),
+    ("LOLCODE", "#cc9900"),
+    ("LookML", "#652B81"),
+    ("LSL", "#3d9970"),
+    ("Lua", "#000080"),
+    ("Luau", "#00A2FF"),
+    ("Makefile", "#427819"),
+    ("Markdown", "#083fa1"),
+    ("Marko", "#42bff2"),
+    ("Mask", "#f97732"),
+    ("MATLAB", "#e16737"),
+    ("Max", "#c4a79c"),
+    ("MAXScript", "#00a6a6"),
+    ("Mercury", "#ff2b2b"),
+    ("Meson", "#007800"),
+    ("Metal", "#8f14e9"),
+    ("Mirah", "#c7a938"),
+    ("MLIR", "#5EC8DB"),
+    ("Modelica", "#de1d31"),
+    ("Mojo", "#ff4c1f"),
+    ("MoonScript", "#ff4585"),
+    ("Move", "#4a137a"),
+    ("MQL4", "#62A8D6"),
+    ("MQL5", "#4A76B8"),
+    ("Nearley", "#990000"),
+    ("Nemerle", "#3d3c6e"),
+    ("nesC", "#94B0C7"),
+    ("NetLogo", "#ff6375"),
+    ("NewLisp", "#87AED7"),
+    ("Nextflow", "#3ac486"),
+    ("Nim", "#ffc200"),
+    ("Nit", "#009917"),
+    ("Nix", "#7e7eff"),
+    ("Nu", "#c9df40"),
+    ("Nunjucks", "#3d8137"),
+    ("Nushell", "#4E9906"),
+    ("Objective-C", "#438eff"),
+    ("Objective-C++", "#6866fb"),
+    ("Objective-J", "#ff0c5a"),
+    ("OCaml", "#ef7a08"),
+    ("Odin", "#60AFFE"),
+    ("ooc", "#b0b77e"),
+    ("Opal", "#f7ede0"),
+    ("OpenSCAD", "#e5cd45"),
+    ("Org", "#77aa99"),
+    ("Oz", "#fab738"),
+    ("Pascal", "#E3F171"),
+    ("Pawn", "#dbb284"),
+    ("Perl", "#0298c3"),
+    ("PHP", "#4F5D95"),
+    ("PigLatin", "#fcd7de"),
+    ("Pike", "#005390"),
+    ("PLpgSQL", "#336790"),
+    ("PLSQL", "#dad8d8"),
+    ("PogoScript", "#d80074"),
+    ("PostScript", "#da291c"),
+    ("PowerBuilder", "#8f0f8d"),
+    ("PowerShell", "#012456"),
+    ("Prisma", "#0c344b"),
+    ("Processing", "#0096D8"),
+    ("Prolog", "#74283c"),
+    ("Pug", "#a86454"),
+    ("Puppet", "#302B6D"),
+    ("PureBasic", "#5a6986"),
+    ("PureScript", "#1D222D"),
+    ("Python", "#3572A5"),
+    ("Q#", "#fed659"),
+    ("QML", "#44a51c"),
+    ("Qt Script", "#00b841"),
+    ("R", "#198CE7"),
+    ("Racket", "#3c5caa"),
+    ("Ragel", "#9d5200"),
+    ("Raku", "#0000fb"),
+    ("RAML", "#77d9fb"),
+    ("Reason", "#ff5847"),
+    ("Rebol", "#358a5b"),
+    ("Red", "#f50000"),
+    ("Ren'Py", "#ff7f7f"),
+    ("ReScript", "#ed5051"),
+    ("Ring", "#2D54CB"),
+    ("Riot", "#A71E49"),
+    ("RobotFramework", "#00c0b5"),
+    ("Roff", "#ecdebe"),
+    ("Ruby", "#701516"),
+    ("Rust", "#dea584"),
+    ("SaltStack", "#646464"),
+    ("SAS", "#B34936"),
+    ("Sass", "#a53b70"),
+    ("Scala", "#c22d40"),
+    ("Scheme", "#1e4aec"),
+    ("SCSS", "#c6538c"),
+    ("Self", "#0579aa"),
+    ("ShaderLab", "#222c37"),
+    ("Shell", "#89e051"),
+    ("Slash", "#007eff"),
+    ("Slim", "#2b2b2b"),
+    ("Smalltalk", "#596706"),
+    ("Solidity", "#AA6746"),
+    ("SourcePawn", "#f69e1d"),
+    ("SQF", "#3F3F3F"),
+    ("Squirrel", "#800000"),
+    ("Stan", "#b2011d"),
+    ("Standard ML", "#dc566d"),
+    ("Starlark", "#76d275"),
+    ("Stylus", "#ff6347"),
+    ("SuperCollider", "#46390b"),
+    ("Svelte", "#ff3e00"),
+    ("SVG", "#ff9900"),
+    ("Swift", "#F05138"),
+    ("SystemVerilog", "#DAE1C2"),
+    ("Tcl", "#e4cc98"),
+    ("TeX", "#3D6117"),
+    ("Terra", "#00004c"),
+    ("Thrift", "#D12127"),
+    ("TOML", "#9c4221"),
+    ("TSQL", "#e38c00"),
+    ("TSX", "#3178c6"),
+    ("Turing", "#cf142b"),
+    ("Twig", "#c1d026"),
+    ("TypeScript", "#3178c6"),
+    ("Typst", "#239dad"),
+    ("Unity3D Asset", "#222c37"),
+    ("UnrealScript", "#a54c4d"),
+    ("V", "#4f87c4"),
+    ("Vala", "#a56de2"),
+    ("VBA", "#867db1"),
+    ("VBScript", "#15dcdc"),
+    ("VCL", "#148AA8"),
+    ("Verilog", "#b2b7f8"),
+    ("VHDL", "#adb2cb"),
+    ("Vim Script", "#199f4b"),
+    ("Visual Basic .NET", "#945db7"),
+    ("Volt", "#1F1F1F"),
+    ("Vue", "#41b883"),
+    ("WebAssembly", "#04133b"),
+    ("WGSL", "#1a5e9a"),
+    ("Wollok", "#a23738"),
+    ("X10", "#4B6BEF"),
+    ("xBase", "#403a40"),
+    ("XC", "#99DA07"),
+    ("XML", "#0060ac"),
+    ("XQuery", "#5232e7"),
+    ("XSLT", "#EB8CEB"),
+    ("Xtend", "#24255d"),
+    ("YAML", "#cb171e"),
+    ("YARA", "#220000"),
+    ("Zap", "#0d665e"),
+    ("Zenscript", "#00BCD1"),
+    ("Zephir", "#118f9e"),
+    ("Zig", "#ec915c"),
+    ("ZIL", "#dc75e5"),
+];
+
+/// Linguist name for the common spellings people and APIs use instead
+fn canonical_language(language: &str) -> &str {
+    match language.to_lowercase().as_str() {
+        "cpp" | "c plus plus" => "C++",
+        "csharp" | "c sharp" => "C#",
+        "fsharp" => "F#",
+        "js" | "node" | "nodejs" => "JavaScript",
+        "ts" => "TypeScript",
+        "py" | "python3" => "Python",
+        "rb" => "Ruby",
+        "golang" => "Go",
+        "rs" => "Rust",
+        "objc" | "objective c" => "Objective-C",
+        "sh" | "bash" | "zsh" => "Shell",
+        "vim" | "viml" | "vimscript" => "Vim Script",
+        "vb.net" | "vbnet" => "Visual Basic .NET",
+        "terraform" => "HCL",
+        "perl6" => "Raku",
+        "asm" => "Assembly",
+        "docker" => "Dockerfile",
+        "make" => "Makefile",
+        "yml" => "YAML",
+        _ => language,
+    }
+}
+
+/// Linguist colour for a language name, case-insensitively
+pub fn language_color(language: &str) -> &'static str {
+    let language = canonical_language(language.trim());
+    LANGUAGE_COLORS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(language))
+        .map(|(_, color)| *color)
+        .unwrap_or(DEFAULT_LANGUAGE_COLOR)
+}
+
+/// Turn byte counts per language into shares of the whole, largest first
+pub fn language_breakdown<I>(bytes: I) -> Vec<LanguageStat>
+where
+    I: IntoIterator<Item = (String, u64)>,
+{
+    let bytes: Vec<(String, u64)> = bytes.into_iter().filter(|(_, b)| *b > 0).collect();
+    let total: u64 = bytes.iter().map(|(_, b)| b).sum();
+    
+    let mut languages: Vec<LanguageStat> = bytes
+        .into_iter()
+        .map(|(name, bytes)| LanguageStat {
+            percentage: bytes as f64 * 100.0 / total as f64,
+            name,
+            bytes,
+        })
+        .collect();
+    sort_languages(&mut languages);
+    languages
+}
+
+/// Shares for forges that only report percentages, largest first
+pub fn language_shares<I>(percentages: I) -> Vec<LanguageStat>
+where
+    I: IntoIterator<Item = (String, f64)>,
+{
+    let mut languages: Vec<LanguageStat> = percentages
+        .into_iter()
+        .filter(|(_, p)| *p > 0.0)
+        .map(|(name, percentage)| LanguageStat { name, bytes: 0, percentage })
+        .collect();
+    sort_languages(&mut languages);
+    languages
+}
+
+fn sort_languages(languages: &mut [LanguageStat]) {
+    languages.sort_by(|a, b| b.percentage.total_cmp(&a.percentage).then_with(|| a.name.cmp(&b.name)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_color() {
+        assert_eq!(language_color("Rust"), "#dea584");
+        assert_eq!(language_color("rust"), "#dea584");
+        assert_eq!(language_color("cpp"), "#f34b7d");
+        assert_eq!(language_color("Jupyter Notebook"), "#DA5B0B");
+        assert_eq!(language_color("Vim Script"), "#199f4b");
+        assert_eq!(language_color("NoSuchLanguage"), DEFAULT_LANGUAGE_COLOR);
+    }
+
+    #[test]
+    fn test_palette_is_well_formed() {
+        for (index, (name, color)) in LANGUAGE_COLORS.iter().enumerate() {
+            assert!(color.len() == 7 && color.starts_with('#'), "Bad colour for {}", name);
+            assert!(color[1..].chars().all(|c| c.is_ascii_hexdigit()), "Bad colour for {}", name);
+            assert!(
+                LANGUAGE_COLORS[index + 1..].iter().all(|(other, _)| !other.eq_ignore_ascii_case(name)),
+                "Duplicate entry for {}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_language_breakdown() {
+        let languages = language_breakdown(vec![
+            ("TypeScript".to_string(), 250),
+            ("Rust".to_string(), 750),
+            ("Empty".to_string(), 0),
+        ]);
+        
+        assert_eq!(languages.len(), 2);
+        assert_eq!(languages[0].name, "Rust");
+        assert_eq!(languages[0].bytes, 750);
+        assert!((languages[0].percentage - 75.0).abs() < f64::EPSILON);
+        assert!((languages[1].percentage - 25.0).abs() < f64::EPSILON);
+        assert!(language_breakdown(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_language_shares() {
+        let languages = language_shares(vec![("Ruby".to_string(), 12.5), ("Go".to_string(), 87.5)]);
+        assert_eq!(languages[0].name, "Go");
+        assert_eq!(languages[0].bytes, 0);
+        assert_eq!(languages[1].name, "Ruby");
+    }
+}
@@ -0,0 +1,408 @@
+// RepoCard Studio - Text Layout Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::templates::escape_xml;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+/// `font-family` of every built-in card: Inter, then Noto for scripts and emoji Inter lacks
+pub const CARD_FONT_FAMILY: &str = "Inter, 'Noto Sans', 'Noto Sans CJK SC', 'Noto Color Emoji', sans-serif";
+/// `font-family` for code-like text in user templates
+pub const MONO_FONT_FAMILY: &str = "'JetBrains Mono', 'Noto Sans Mono', monospace";
+/// Named families of `CARD_FONT_FAMILY`, in order, so measuring picks the face resvg draws with
+const CARD_FAMILIES: [&str; 4] = ["Inter", "Noto Sans", "Noto Sans CJK SC", "Noto Color Emoji"];
+/// Families tried for `sans-serif`, so measuring and rasterizing pick the same face
+const SANS_SERIF_FAMILIES: [&str; 7] = ["Inter", "Arial", "Helvetica", "Segoe UI", "DejaVu Sans", "Liberation Sans", "Noto Sans"];
+/// Families tried for `monospace`
+const MONOSPACE_FAMILIES: [&str; 4] = ["JetBrains Mono", "DejaVu Sans Mono", "Liberation Mono", "Noto Sans Mono"];
+const ELLIPSIS: &str = "…";
+
+const FONTS_DIR_NAME: &str = "repocard-studio";
+/// Overrides where font files are loaded from
+pub const FONTS_DIR_ENV: &str = "REPOCARD_FONTS_DIR";
+/// Set to `0` to render with only the fonts directory and embedded fonts, e.g. for identical CI output
+pub const SYSTEM_FONTS_ENV: &str = "REPOCARD_SYSTEM_FONTS";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontWeight {
+    Normal,
+    Bold,
+}
+
+struct Fonts {
+    db: Arc<fontdb::Database>,
+    /// What `sans-serif` resolves to, also used for text that names no font
+    sans_serif: Option<String>,
+    regular: Option<fontdb::ID>,
+    bold: Option<fontdb::ID>,
+}
+
+impl Fonts {
+    fn new(mut db: fontdb::Database) -> Self {
+        let installed = |db: &fontdb::Database, families: &[&str]| {
+            families
+                .iter()
+                .find(|family| db.faces().any(|face| face.families.iter().any(|(name, _)| name == *family)))
+                .map(|family| family.to_string())
+        };
+        let sans_serif = installed(&db, &SANS_SERIF_FAMILIES);
+        if let Some(family) = &sans_serif {
+            db.set_sans_serif_family(family);
+        }
+        if let Some(family) = installed(&db, &MONOSPACE_FAMILIES) {
+            db.set_monospace_family(family);
+        }
+        
+        let families: Vec<fontdb::Family> = CARD_FAMILIES
+            .iter()
+            .map(|family| fontdb::Family::Name(family))
+            .chain([fontdb::Family::SansSerif])
+            .collect();
+        let face = |weight| {
+            db.query(&fontdb::Query {
+                families: &families,
+                weight,
+                ..fontdb::Query::default()
+            })
+        };
+        let (regular, bold) = (face(fontdb::Weight::NORMAL), face(fontdb::Weight::BOLD));
+        Fonts { db: Arc::new(db), sans_serif, regular, bold }
+    }
+}
+
+/// Fonts from the fonts directory, then the system's unless `REPOCARD_SYSTEM_FONTS=0`
+fn fonts_lock() -> &'static RwLock<Arc<Fonts>> {
+    static FONTS: OnceLock<RwLock<Arc<Fonts>>> = OnceLock::new();
+    FONTS.get_or_init(|| {
+        let mut db = fontdb::Database::new();
+        if let Some(dir) = user_fonts_dir().filter(|dir| dir.is_dir()) {
+            db.load_fonts_dir(dir);
+        }
+        if std::env::var(SYSTEM_FONTS_ENV).map_or(true, |value| value != "0") {
+            db.load_system_fonts();
+        }
+        RwLock::new(Arc::new(Fonts::new(db)))
+    })
+}
+
+fn fonts() -> Arc<Fonts> {
+    fonts_lock().read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Add fonts to the shared database; renders already under way keep the old one
+fn add_fonts(load: impl FnOnce(&mut fontdb::Database)) {
+    let mut fonts = fonts_lock().write().unwrap_or_else(|e| e.into_inner());
+    let mut db = (*fonts.db).clone();
+    load(&mut db);
+    *fonts = Arc::new(Fonts::new(db));
+}
+
+/// Where font files are loaded from: `REPOCARD_FONTS_DIR`, else `<config dir>/repocard-studio/fonts`
+pub fn user_fonts_dir() -> Option<PathBuf> {
+    match std::env::var_os(FONTS_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => dirs::config_dir().map(|dir| dir.join(FONTS_DIR_NAME).join("fonts")),
+    }
+}
+
+/// Load every font file in `dir`, e.g. one shipped next to the binary
+pub fn load_fonts_dir(dir: &Path) {
+    add_fonts(|db| db.load_fonts_dir(dir));
+}
+
+/// Register a font compiled into the binary, e.g. `embed_font(include_bytes!("Inter.ttf"))`
+pub fn embed_font(data: &'static [u8]) {
+    add_fonts(|db| {
+        db.load_font_source(fontdb::Source::Binary(Arc::new(data)));
+    });
+}
+
+/// Loaded fonts, shared by text layout and rasterizing
+pub fn font_database() -> Arc<fontdb::Database> {
+    fonts().db.clone()
+}
+
+/// Family names of every loaded face, sorted
+pub fn font_families() -> Vec<String> {
+    let db = font_database();
+    let mut families: Vec<String> = db.faces().flat_map(|face| face.families.iter().map(|(name, _)| name.clone())).collect();
+    families.sort();
+    families.dedup();
+    families
+}
+
+/// usvg options that resolve fonts from the shared database
+pub fn render_options() -> usvg::Options<'static> {
+    let fonts = fonts();
+    let mut options = usvg::Options {
+        fontdb: fonts.db.clone(),
+        ..usvg::Options::default()
+    };
+    if let Some(family) = &fonts.sans_serif {
+        options.font_family = family.clone();
+    }
+    options
+}
+
+/// The SVG with every `<text>` replaced by glyph outlines, so it looks the same without the fonts
+pub fn outline_text(svg: &str) -> Result<String, AppError> {
+    let tree = usvg::Tree::from_str(svg, &render_options())
+        .map_err(|e| AppError::render(format!("Failed to parse SVG: {}", e)))?;
+    Ok(tree.to_string(&usvg::WriteOptions::default()))
+}
+
+/// Advance width of `text` in SVG units, shaped with the font cards are drawn in
+pub fn text_width(text: &str, font_size: f32, weight: FontWeight) -> f32 {
+    let fonts = fonts();
+    let face = match weight {
+        FontWeight::Normal => fonts.regular,
+        FontWeight::Bold => fonts.bold.or(fonts.regular),
+    };
+    face.and_then(|id| fonts.db.with_face_data(id, |data, index| shaped_width(text, data, index)))
+        .flatten()
+        .map(|em| em * font_size)
+        .unwrap_or_else(|| estimated_width(text, weight) * font_size)
+}
+
+/// Width in ems; glyphs the face lacks are estimated, as resvg falls back to another font for them
+fn shaped_width(text: &str, data: &[u8], index: u32) -> Option<f32> {
+    let face = rustybuzz::Face::from_slice(data, index)?;
+    let units_per_em = face.units_per_em() as f32;
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+    buffer.push_str(text);
+    let glyphs = rustybuzz::shape(&face, &[], buffer);
+    
+    let width = glyphs
+        .glyph_infos()
+        .iter()
+        .zip(glyphs.glyph_positions())
+        .map(|(info, position)| match info.glyph_id {
+            0 => text[info.cluster as usize..].chars().next().map(char_em).unwrap_or(0.0),
+            _ => position.x_advance as f32 / units_per_em,
+        })
+        .sum();
+    Some(width)
+}
+
+/// Width in ems without any font, from each character's display width
+fn estimated_width(text: &str, weight: FontWeight) -> f32 {
+    let scale = if weight == FontWeight::Bold { 1.1 } else { 1.0 };
+    text.chars().map(char_em).sum::<f32>() * scale
+}
+
+/// Full-width characters (CJK, emoji) take an em, combining marks nothing, the rest about half
+fn char_em(c: char) -> f32 {
+    match c.width() {
+        Some(2) => 1.0,
+        Some(0) | None => 0.0,
+        _ if c.is_whitespace() => 0.28,
+        _ => 0.55,
+    }
+}
+
+/// Text laid out in a box: at most `max_lines` lines of `width`, shrinking from
+/// `font_size` towards `min_font_size` before the last line is cut with an ellipsis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextBox {
+    pub width: f32,
+    pub max_lines: usize,
+    pub font_size: f32,
+    pub min_font_size: f32,
+    pub weight: FontWeight,
+}
+
+/// The lines a `TextBox` produced and the size they fit at
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextBlock {
+    pub lines: Vec<String>,
+    pub font_size: f32,
+}
+
+impl TextBox {
+    /// A single line at a fixed size
+    pub fn line(width: f32, font_size: f32, weight: FontWeight) -> Self {
+        Self { width, max_lines: 1, font_size, min_font_size: font_size, weight }
+    }
+
+    pub fn fit(&self, text: &str) -> TextBlock {
+        let text = text.trim();
+        let mut font_size = self.font_size;
+        loop {
+            let lines = wrap_text(text, self.width, font_size, self.weight);
+            let at_minimum = font_size - 1.0 < self.min_font_size;
+            if lines.len() <= self.max_lines || at_minimum {
+                return TextBlock { lines: self.clamp_lines(text, lines, font_size), font_size };
+            }
+            font_size -= 1.0;
+        }
+    }
+
+    /// Keep `max_lines` lines, ending the last with an ellipsis when text was left over
+    fn clamp_lines(&self, text: &str, lines: Vec<(usize, usize)>, font_size: f32) -> Vec<String> {
+        let keep = self.max_lines.max(1);
+        let overflow = lines.len() > keep;
+        lines
+            .iter()
+            .take(keep)
+            .enumerate()
+            .map(|(index, &(start, end))| {
+                if overflow && index + 1 == keep {
+                    ellipsize(&format!("{}{}", text[start..].trim(), ELLIPSIS), self.width, font_size, self.weight)
+                } else {
+                    text[start..end].trim().to_string()
+                }
+            })
+            .collect()
+    }
+}
+
+impl TextBlock {
+    /// `<tspan>`s for the lines, the first on the parent `<text>`'s baseline
+    pub fn tspans(&self, x: f32, line_height: f32) -> String {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let dy = if index == 0 { 0.0 } else { self.font_size * line_height };
+                format!(r#"<tspan x="{}" dy="{}">{}</tspan>"#, x, round(dy), escape_xml(line))
+            })
+            .collect()
+    }
+}
+
+/// One decimal is plenty for SVG coordinates
+pub fn round(value: f32) -> f32 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Byte ranges of the lines `text` wraps into at `max_width`. Lines break where
+/// Unicode allows; a word wider than the box is split between graphemes.
+pub fn wrap_text(text: &str, max_width: f32, font_size: f32, weight: FontWeight) -> Vec<(usize, usize)> {
+    let fits = |start: usize, end: usize| text_width(text[start..end].trim_end(), font_size, weight) <= max_width;
+    let mut lines = Vec::new();
+    let (mut line_start, mut line_end) = (0, 0);
+    
+    for (position, opportunity) in linebreaks(text) {
+        if line_end > line_start && !fits(line_start, position) {
+            lines.push((line_start, line_end));
+            line_start = line_end;
+        }
+        // A single word that is too long on its own
+        while !fits(line_start, position) {
+            let split = longest_fitting(text, line_start, position, &fits);
+            lines.push((line_start, split));
+            line_start = split;
+        }
+        line_end = position;
+        if opportunity == BreakOpportunity::Mandatory {
+            lines.push((line_start, line_end));
+            line_start = position;
+        }
+    }
+    lines.retain(|&(start, end)| !text[start..end].trim().is_empty());
+    lines
+}
+
+/// The furthest grapheme boundary after `start` that still fits, at least one grapheme on
+fn longest_fitting(text: &str, start: usize, end: usize, fits: &dyn Fn(usize, usize) -> bool) -> usize {
+    let boundaries: Vec<usize> = text[start..end].grapheme_indices(true).skip(1).map(|(i, _)| start + i).collect();
+    match boundaries.partition_point(|&boundary| fits(start, boundary)) {
+        0 => boundaries.first().copied().unwrap_or(end),
+        count => boundaries[count - 1],
+    }
+}
+
+/// `text` cut to fit `max_width`, with an ellipsis in place of what was cut
+pub fn ellipsize(text: &str, max_width: f32, font_size: f32, weight: FontWeight) -> String {
+    let text = text.trim();
+    if text_width(text, font_size, weight) <= max_width {
+        return text.to_string();
+    }
+    let body = text.strip_suffix(ELLIPSIS).unwrap_or(text);
+    let boundaries: Vec<usize> = body.grapheme_indices(true).map(|(i, _)| i).chain([body.len()]).collect();
+    let with_ellipsis = |end: usize| format!("{}{}", body[..end].trim_end(), ELLIPSIS);
+    let count = boundaries.partition_point(|&end| text_width(&with_ellipsis(end), font_size, weight) <= max_width);
+    with_ellipsis(boundaries[count.saturating_sub(1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LONG: &str = "A developer-first utility for generating polished repository social cards, README snippets, release notes and press kits from any forge";
+
+    #[test]
+    fn test_measures_wider_text_as_wider() {
+        assert_eq!(text_width("", 20.0, FontWeight::Normal), 0.0);
+        let narrow = text_width("iiii", 20.0, FontWeight::Normal);
+        let wide = text_width("WWWW", 20.0, FontWeight::Normal);
+        assert!(narrow > 0.0 && wide > narrow * 1.5, "{} vs {}", narrow, wide);
+        assert!(text_width("WWWW", 40.0, FontWeight::Normal) > wide * 1.9);
+        assert!(text_width("漢字漢字", 20.0, FontWeight::Normal) >= 60.0);
+    }
+
+    #[test]
+    fn test_wraps_within_the_box() {
+        let text_box = TextBox { width: 500.0, max_lines: 3, font_size: 20.0, min_font_size: 20.0, weight: FontWeight::Normal };
+        let block = text_box.fit(LONG);
+        assert_eq!(block.font_size, 20.0);
+        assert!(block.lines.len() > 1 && block.lines.len() <= 3, "{:?}", block.lines);
+        for line in &block.lines {
+            assert!(text_width(line, 20.0, FontWeight::Normal) <= 500.0, "{:?} overflows", line);
+            assert_eq!(line.trim(), line);
+        }
+        assert!(block.lines[0].starts_with("A developer-first"));
+    }
+
+    #[test]
+    fn test_shrinks_then_ellipsizes() {
+        let shrinking = TextBox { width: 900.0, max_lines: 2, font_size: 28.0, min_font_size: 14.0, weight: FontWeight::Normal };
+        let block = shrinking.fit(LONG);
+        assert!(block.font_size < 28.0 && block.font_size >= 14.0);
+        assert!(!block.lines.concat().contains(ELLIPSIS));
+        
+        let block = TextBox::line(300.0, 20.0, FontWeight::Bold).fit(LONG);
+        assert_eq!(block.lines.len(), 1);
+        assert!(block.lines[0].ends_with(ELLIPSIS));
+        assert!(text_width(&block.lines[0], 20.0, FontWeight::Bold) <= 300.0);
+    }
+
+    #[test]
+    fn test_splits_words_wider_than_the_box() {
+        let url = "https://example.com/a/very/long/path/without/any/spaces/at/all/in/it";
+        let lines = wrap_text(url, 150.0, 16.0, FontWeight::Normal);
+        assert!(lines.len() > 2);
+        assert_eq!(lines.iter().map(|&(s, e)| &url[s..e]).collect::<String>(), url);
+        
+        let cjk = "漢字かな交じり文の説明がとても長い場合でも枠に収まります";
+        for &(start, end) in &wrap_text(cjk, 120.0, 16.0, FontWeight::Normal) {
+            assert!(text_width(&cjk[start..end], 16.0, FontWeight::Normal) <= 120.0);
+        }
+        assert_eq!(wrap_text("first\nsecond", 1000.0, 16.0, FontWeight::Normal).len(), 2);
+        assert!(wrap_text("   ", 100.0, 16.0, FontWeight::Normal).is_empty());
+    }
+
+    #[test]
+    fn test_tspans_escape_and_step_lines() {
+        let block = TextBlock { lines: vec!["a < b".to_string(), "c".to_string()], font_size: 20.0 };
+        assert_eq!(block.tspans(80.0, 1.3), r#"<tspan x="80" dy="0">a &lt; b</tspan><tspan x="80" dy="26">c</tspan>"#);
+    }
+
+    #[test]
+    fn test_outlines_text_into_paths() {
+        let svg = format!(
+            r#"<svg width="200" height="50" xmlns="http://www.w3.org/2000/svg"><text x="10" y="30" font-size="20" font-family="{}">Repo</text></svg>"#,
+            CARD_FONT_FAMILY.replace('\'', "&apos;")
+        );
+        let outlined = outline_text(&svg).unwrap();
+        assert!(!outlined.contains("<text"), "{}", outlined);
+        if !font_families().is_empty() {
+            assert!(outlined.contains("<path"), "{}", outlined);
+        }
+        assert_eq!(outline_text("not svg").unwrap_err().kind(), "render");
+    }
+}
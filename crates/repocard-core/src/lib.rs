@@ -0,0 +1,300 @@
+// RepoCard Studio - Core Library
+// LAZYFROG (KZ) — kindware.dev
+
+//! Repository fetching, card templates and share kit export, without any GUI.
+//!
+//! The desktop app wraps these in Tauri commands; the `repocard` CLI and other
+//! Rust services can call them directly.
+
+use serde::{Deserialize, Serialize};
+
+mod avatar;
+mod cache;
+mod config;
+mod error;
+mod gitea;
+mod github;
+mod gitlab;
+mod languages;
+mod layout;
+mod local;
+mod provider;
+mod raster;
+mod repo_ref;
+mod sizes;
+mod export;
+mod template_registry;
+mod templates;
+mod user_templates;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
+pub use avatar::*;
+pub use cache::*;
+pub use config::*;
+pub use error::*;
+pub use gitea::*;
+pub use github::*;
+pub use gitlab::*;
+pub use languages::*;
+pub use layout::*;
+pub use local::*;
+pub use provider::*;
+pub use raster::*;
+pub use repo_ref::*;
+pub use sizes::*;
+pub use export::*;
+pub use template_registry::*;
+pub use templates::*;
+pub use user_templates::*;
+
+/// Repository metadata from GitHub API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoMetadata {
+    pub name: String,
+    pub full_name: String,
+    pub description: Option<String>,
+    pub html_url: String,
+    pub stargazers_count: u32,
+    pub forks_count: u32,
+    pub watchers_count: u32,
+    pub open_issues_count: u32,
+    pub language: Option<String>,
+    pub topics: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pushed_at: String,
+    pub default_branch: String,
+    pub license: Option<LicenseInfo>,
+    pub owner: OwnerInfo,
+    /// Tag of the most recent published release, if any
+    #[serde(default)]
+    pub latest_release: Option<String>,
+    /// Top contributors, most contributions first
+    #[serde(default)]
+    pub contributors: Vec<ContributorInfo>,
+    /// Byte-weighted language breakdown, largest share first
+    #[serde(default)]
+    pub languages: Vec<LanguageStat>,
+    /// The repository's `.repocard.toml`, already applied to the fields above
+    #[serde(default)]
+    pub project_config: Option<ProjectConfig>,
+}
+
+/// Someone who has contributed commits to the repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorInfo {
+    /// Forge username, or the commit author name where the forge has no accounts to match
+    pub login: String,
+    /// Empty when no avatar is known
+    pub avatar_url: String,
+    /// Empty when there is no profile page
+    pub html_url: String,
+    pub contributions: u32,
+}
+
+/// One language's share of the repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageStat {
+    pub name: String,
+    /// Zero where the forge only reports percentages
+    pub bytes: u64,
+    pub percentage: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseInfo {
+    pub key: String,
+    pub name: String,
+    pub spdx_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnerInfo {
+    pub login: String,
+    pub avatar_url: String,
+    pub html_url: String,
+    /// The avatar embedded as a `data:` URI, so SVG and PNG cards show it without network access
+    #[serde(default)]
+    pub avatar_data_uri: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitInfo {
+    pub sha: String,
+    pub message: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub date: String,
+    /// Forge account the commit is attributed to, when the forge links one
+    #[serde(default)]
+    pub author_login: Option<String>,
+}
+
+impl CommitInfo {
+    /// Key matching this commit to a `ContributorInfo`
+    pub fn contributor_key(&self) -> &str {
+        self.author_login.as_deref().unwrap_or(&self.author_name)
+    }
+}
+
+/// A published release and its downloadable assets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub html_url: String,
+    /// Unset for drafts
+    pub published_at: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    /// Size in bytes; 0 when the forge does not report it
+    pub size: u64,
+    /// 0 when the forge does not count downloads
+    pub download_count: u64,
+    pub download_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagInfo {
+    pub name: String,
+    /// Full SHA of the tagged commit
+    pub sha: String,
+}
+
+/// Which commits to fetch: a branch, a `base..head` range and/or a date window
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommitRange {
+    /// Branch, tag or SHA to read history from; the default branch when unset
+    pub head: Option<String>,
+    /// Leave out commits reachable from this tag or SHA, e.g. the previous release
+    pub base: Option<String>,
+    /// Only commits on or after this date (RFC 3339 or `YYYY-MM-DD`)
+    pub since: Option<String>,
+    /// Only commits on or before this date (RFC 3339 or `YYYY-MM-DD`)
+    pub until: Option<String>,
+    /// Stop after this many commits; otherwise every commit in the range is fetched
+    pub limit: Option<u32>,
+}
+
+/// Account and permissions behind a GitHub token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInfo {
+    pub login: String,
+    pub scopes: Vec<String>,
+    pub can_read_private_repos: bool,
+    pub expires_at: Option<String>,
+}
+
+/// GitHub API rate limit state, so the UI can show a countdown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    pub limit: Option<u32>,
+    pub remaining: Option<u32>,
+    /// RFC 3339 timestamp when requests may resume
+    pub resets_at: Option<String>,
+    pub retry_after_secs: Option<u64>,
+    pub limited: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOptions {
+    pub output_dir: String,
+    pub include_attribution: bool,
+    pub template_id: String,
+    pub primary_color: Option<String>,
+    pub secondary_color: Option<String>,
+    /// Add a row of contributor avatars to the card
+    #[serde(default)]
+    pub show_contributors: bool,
+    /// Add a stacked language bar with a legend to the card
+    #[serde(default)]
+    pub show_languages: bool,
+    /// Write the SVG card with text as glyph outlines, so it looks the same without the fonts
+    #[serde(default)]
+    pub outline_text: bool,
+    /// Platform size the card is laid out for
+    #[serde(default)]
+    pub size: SizePreset,
+    /// Also write the card in every size preset, as `<card>-<size>.svg` and the raster format
+    #[serde(default)]
+    pub all_sizes: bool,
+    /// Format, quality and size limit of the raster card next to the SVG
+    #[serde(default)]
+    pub image: RasterOptions,
+    /// Release notes sections; the built-in feature/fix/docs/maintenance ones when empty
+    #[serde(default)]
+    pub release_note_categories: Vec<ReleaseNoteCategory>,
+    #[serde(default)]
+    pub layout: ShareKitLayout,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            output_dir: ".".to_string(),
+            include_attribution: true,
+            template_id: "modern".to_string(),
+            primary_color: None,
+            secondary_color: None,
+            show_contributors: false,
+            show_languages: false,
+            outline_text: false,
+            size: SizePreset::default(),
+            all_sizes: false,
+            image: RasterOptions::default(),
+            release_note_categories: Vec::new(),
+            layout: ShareKitLayout::default(),
+        }
+    }
+}
+
+/// Release notes section collecting commits whose message starts with one of `prefixes`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseNoteCategory {
+    pub title: String,
+    /// Matched case-insensitively against the start of the commit message
+    pub prefixes: Vec<String>,
+}
+
+/// File names inside the exported share kit, relative to `folder`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShareKitLayout {
+    /// Created inside the export directory
+    pub folder: String,
+    /// File name without extension, shared by the SVG and raster card
+    pub card: String,
+    pub readme: String,
+    pub release_notes: String,
+    pub press_kit: String,
+    pub screenshots: String,
+}
+
+impl Default for ShareKitLayout {
+    fn default() -> Self {
+        Self {
+            folder: "share-kit".to_string(),
+            card: "repo-card".to_string(),
+            readme: "README-snippet.md".to_string(),
+            release_notes: "release-notes-draft.md".to_string(),
+            press_kit: "press-kit/overview.md".to_string(),
+            screenshots: "press-kit/screenshots".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportResult {
+    pub success: bool,
+    pub output_path: String,
+    pub files: Vec<String>,
+    pub error: Option<String>,
+}
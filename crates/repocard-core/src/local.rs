@@ -0,0 +1,602 @@
+// RepoCard Studio - Local Checkout Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::repo_ref::RepoRef;
+use crate::provider::CONTRIBUTOR_LIMIT;
+use crate::{language_breakdown, CommitInfo, CommitRange, ContributorInfo, LanguageStat, LicenseInfo, OwnerInfo};
+use crate::{ProjectConfig, RepoMetadata, TagInfo, PROJECT_CONFIG_FILE};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directories never worth scanning when guessing the language
+const SKIPPED_DIRS: [&str; 10] = [
+    ".git", "node_modules", "target", "dist", "build", "vendor", ".venv", "__pycache__", ".next", "out",
+];
+/// Upper bound on files inspected for the language guess
+const MAX_SCANNED_FILES: usize = 20_000;
+const LICENSE_FILES: [&str; 8] = [
+    "LICENSE", "LICENSE.md", "LICENSE.txt", "LICENCE", "LICENCE.md", "LICENCE.txt", "COPYING", "COPYING.md",
+];
+const README_FILES: [&str; 4] = ["README.md", "README", "README.txt", "readme.md"];
+
+/// Whether a repository reference points at a directory on disk rather than a forge
+pub fn is_local_path(repo_ref: &str) -> bool {
+    let repo_ref = repo_ref.trim();
+    if repo_ref.starts_with("file://") {
+        return true;
+    }
+    let explicit = repo_ref.starts_with('.') || repo_ref.starts_with('~') || Path::new(repo_ref).is_absolute();
+    explicit && resolve_path(repo_ref).is_dir()
+}
+
+/// Turn `file://`, `~/` and relative references into a filesystem path
+fn resolve_path(repo_ref: &str) -> PathBuf {
+    let repo_ref = repo_ref.trim();
+    let repo_ref = repo_ref.strip_prefix("file://").unwrap_or(repo_ref);
+    match repo_ref.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(repo_ref),
+    }
+}
+
+/// Run a git command in the checkout, returning trimmed stdout on success
+fn git(repo: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git").arg("-C").arg(repo).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Normalise a git timestamp to the UTC RFC 3339 form the forge APIs use
+fn normalize_date(date: &str) -> Option<String> {
+    DateTime::parse_from_rfc3339(date.trim())
+        .ok()
+        .map(|d| d.with_timezone(&Utc).to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+/// The `[package]` table of Cargo.toml, if there is one
+fn cargo_manifest(root: &Path) -> Option<toml::Table> {
+    let manifest: toml::Table = fs::read_to_string(root.join("Cargo.toml")).ok()?.parse().ok()?;
+    manifest.get("package")?.as_table().cloned()
+}
+
+fn package_json(root: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&fs::read_to_string(root.join("package.json")).ok()?).ok()
+}
+
+/// First prose paragraph of the README, skipping headings, badges and HTML
+fn readme_description(root: &Path) -> Option<String> {
+    let readme = README_FILES
+        .iter()
+        .find_map(|name| fs::read_to_string(root.join(name)).ok())?;
+    
+    let mut paragraph: Vec<&str> = Vec::new();
+    for line in readme.lines().map(str::trim) {
+        let is_prose = !line.is_empty()
+            && !line.starts_with('#')
+            && !line.starts_with('<')
+            && !line.starts_with("[![")
+            && !line.starts_with("![")
+            && !line.starts_with("---")
+            && !line.starts_with("```");
+        if is_prose {
+            paragraph.push(line.trim_matches('*').trim());
+        } else if !paragraph.is_empty() {
+            break;
+        }
+    }
+    
+    let description = paragraph.join(" ");
+    if description.is_empty() {
+        None
+    } else {
+        Some(description)
+    }
+}
+
+/// Identify a license from the text of a LICENSE file
+fn detect_license_text(text: &str) -> Option<(&'static str, &'static str)> {
+    let text = text.to_lowercase();
+    let has = |needle: &str| text.contains(needle);
+    
+    let license = if has("gnu affero general public license") {
+        ("AGPL-3.0", "GNU Affero General Public License v3.0")
+    } else if has("gnu lesser general public license") {
+        if has("version 2.1") {
+            ("LGPL-2.1", "GNU Lesser General Public License v2.1")
+        } else {
+            ("LGPL-3.0", "GNU Lesser General Public License v3.0")
+        }
+    } else if has("gnu general public license") {
+        if has("version 2,") || has("version 2 ") {
+            ("GPL-2.0", "GNU General Public License v2.0")
+        } else {
+            ("GPL-3.0", "GNU General Public License v3.0")
+        }
+    } else if has("apache license") && has("version 2.0") {
+        ("Apache-2.0", "Apache License 2.0")
+    } else if has("mozilla public license") && has("2.0") {
+        ("MPL-2.0", "Mozilla Public License 2.0")
+    } else if has("permission is hereby granted, free of charge") {
+        ("MIT", "MIT License")
+    } else if has("unencumbered software released into the public domain") {
+        ("Unlicense", "The Unlicense")
+    } else if has("permission to use, copy, modify, and/or distribute") {
+        ("ISC", "ISC License")
+    } else if has("redistribution and use in source and binary forms") {
+        if has("neither the name") || has("names of its contributors") {
+            ("BSD-3-Clause", "BSD 3-Clause \"New\" or \"Revised\" License")
+        } else {
+            ("BSD-2-Clause", "BSD 2-Clause \"Simplified\" License")
+        }
+    } else {
+        return None;
+    };
+    
+    Some(license)
+}
+
+/// License from a LICENSE file, falling back to the manifest's SPDX expression
+fn detect_license(root: &Path, manifest_license: Option<String>) -> Option<LicenseInfo> {
+    let from_file = LICENSE_FILES
+        .iter()
+        .find_map(|name| fs::read_to_string(root.join(name)).ok())
+        .and_then(|text| detect_license_text(&text));
+    
+    match (from_file, manifest_license) {
+        (Some((spdx, name)), _) => Some(LicenseInfo {
+            key: spdx.to_lowercase(),
+            name: name.to_string(),
+            spdx_id: Some(spdx.to_string()),
+        }),
+        (None, Some(spdx)) => Some(LicenseInfo {
+            key: spdx.to_lowercase(),
+            name: spdx.clone(),
+            spdx_id: Some(spdx),
+        }),
+        (None, None) => None,
+    }
+}
+
+/// Language for a file extension, for the subset of linguist we care about
+fn language_for_extension(extension: &str) -> Option<&'static str> {
+    let language = match extension.to_lowercase().as_str() {
+        "rs" => "Rust",
+        "ts" | "tsx" | "mts" | "cts" => "TypeScript",
+        "js" | "jsx" | "mjs" | "cjs" => "JavaScript",
+        "py" | "pyi" => "Python",
+        "go" => "Go",
+        "java" => "Java",
+        "kt" | "kts" => "Kotlin",
+        "swift" => "Swift",
+        "rb" => "Ruby",
+        "php" => "PHP",
+        "c" | "h" => "C",
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" | "hxx" => "C++",
+        "cs" => "C#",
+        "dart" => "Dart",
+        "vue" => "Vue",
+        "svelte" => "Svelte",
+        "html" | "htm" => "HTML",
+        "css" => "CSS",
+        "scss" | "sass" => "SCSS",
+        "sh" | "bash" | "zsh" => "Shell",
+        "ps1" => "PowerShell",
+        "lua" => "Lua",
+        "zig" => "Zig",
+        "ex" | "exs" => "Elixir",
+        "erl" => "Erlang",
+        "hs" => "Haskell",
+        "scala" => "Scala",
+        "clj" | "cljs" => "Clojure",
+        "ml" | "mli" => "OCaml",
+        "r" => "R",
+        "jl" => "Julia",
+        "nim" => "Nim",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Files to consider: tracked files when git is available, otherwise a filtered walk
+fn source_files(root: &Path) -> Vec<PathBuf> {
+    if let Some(listing) = git(root, &["ls-files", "-z"]) {
+        return listing
+            .split('\0')
+            .filter(|f| !f.is_empty())
+            .take(MAX_SCANNED_FILES)
+            .map(|f| root.join(f))
+            .collect();
+    }
+    
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            match entry.file_type() {
+                Ok(t) if t.is_dir() && !name.starts_with('.') && !SKIPPED_DIRS.contains(&name.as_str()) => {
+                    pending.push(path);
+                }
+                Ok(t) if t.is_file() => files.push(path),
+                _ => {}
+            }
+            if files.len() >= MAX_SCANNED_FILES {
+                return files;
+            }
+        }
+    }
+    files
+}
+
+/// Language breakdown by total bytes per recognised extension
+fn read_local_languages(root: &Path) -> Vec<LanguageStat> {
+    let mut bytes: HashMap<&'static str, u64> = HashMap::new();
+    for file in source_files(root) {
+        let language = file
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(language_for_extension);
+        if let Some(language) = language {
+            let size = fs::metadata(&file).map(|m| m.len()).unwrap_or(0);
+            *bytes.entry(language).or_default() += size;
+        }
+    }
+    
+    language_breakdown(bytes.into_iter().map(|(language, size)| (language.to_string(), size)))
+}
+
+fn string_list(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|items| items.iter().filter_map(|i| i.as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+/// Build repository metadata from a local checkout without any network access
+pub fn read_local_metadata(repo_ref: &str) -> Result<RepoMetadata, AppError> {
+    let path = resolve_path(repo_ref);
+    if !path.is_dir() {
+        return Err(AppError::not_found(format!("Local repository not found: {}", path.display())));
+    }
+    let root = git(&path, &["rev-parse", "--show-toplevel"])
+        .map(PathBuf::from)
+        .unwrap_or(path);
+    
+    let cargo = cargo_manifest(&root);
+    let npm = package_json(&root);
+    let cargo_str = |key: &str| cargo.as_ref().and_then(|p| p.get(key)).and_then(|v| v.as_str()).map(str::to_string);
+    let npm_str = |key: &str| npm.as_ref().and_then(|p| p.get(key)).and_then(|v| v.as_str()).map(str::to_string);
+    
+    let remote = git(&root, &["remote", "get-url", "origin"]).and_then(|r| RepoRef::parse(&r).ok());
+    
+    // Prefer the remote's owner/name; otherwise the directory name and git user
+    let (owner, name, html_url, owner_url) = match &remote {
+        Some(remote) => (
+            remote.owner.clone(),
+            remote.repo.clone(),
+            remote.web_url(),
+            format!("https://{}/{}", remote.host, remote.owner),
+        ),
+        None => {
+            let owner = git(&root, &["config", "user.name"])
+                .filter(|n| !n.is_empty())
+                .or_else(|| std::env::var("USER").ok())
+                .or_else(|| std::env::var("USERNAME").ok())
+                .unwrap_or_else(|| "local".to_string());
+            let name = root
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "repository".to_string());
+            let url = format!("file://{}", root.display());
+            (owner, name, url.clone(), url)
+        }
+    };
+    
+    let description = cargo_str("description")
+        .or_else(|| npm_str("description"))
+        .or_else(|| readme_description(&root))
+        .filter(|d| !d.trim().is_empty());
+    
+    let mut topics = string_list(cargo.as_ref().and_then(|p| p.get("keywords")));
+    if topics.is_empty() {
+        topics = npm
+            .as_ref()
+            .and_then(|p| p.get("keywords"))
+            .and_then(|k| k.as_array())
+            .map(|k| k.iter().filter_map(|t| t.as_str().map(str::to_string)).collect())
+            .unwrap_or_default();
+    }
+    
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let last_commit = git(&root, &["log", "-1", "--format=%cI"]).and_then(|d| normalize_date(&d));
+    let first_commit = git(&root, &["rev-list", "--max-parents=0", "HEAD"])
+        .and_then(|shas| shas.lines().last().map(str::to_string))
+        .and_then(|sha| git(&root, &["show", "-s", "--format=%cI", &sha]))
+        .and_then(|d| normalize_date(&d));
+    let updated_at = last_commit.unwrap_or_else(|| now.clone());
+    
+    let default_branch = git(&root, &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"])
+        .and_then(|b| b.strip_prefix("origin/").map(str::to_string))
+        .or_else(|| git(&root, &["rev-parse", "--abbrev-ref", "HEAD"]).filter(|b| b != "HEAD"))
+        .unwrap_or_else(|| "main".to_string());
+    
+    let languages = read_local_languages(&root);
+    
+    Ok(RepoMetadata {
+        full_name: format!("{}/{}", owner, name),
+        name,
+        description,
+        html_url,
+        stargazers_count: 0,
+        forks_count: 0,
+        watchers_count: 0,
+        open_issues_count: 0,
+        language: languages.first().map(|l| l.name.clone()),
+        topics,
+        created_at: first_commit.unwrap_or_else(|| updated_at.clone()),
+        pushed_at: updated_at.clone(),
+        updated_at,
+        default_branch,
+        license: detect_license(&root, cargo_str("license").or_else(|| npm_str("license"))),
+        owner: OwnerInfo {
+            login: owner,
+            avatar_url: String::new(),
+            avatar_data_uri: None,
+            html_url: owner_url,
+        },
+        // Without a forge the nearest tag stands in for the latest release
+        latest_release: git(&root, &["describe", "--tags", "--abbrev=0"]),
+        contributors: read_local_contributors(&root.to_string_lossy(), CONTRIBUTOR_LIMIT).unwrap_or_default(),
+        languages,
+        project_config: None,
+    })
+}
+
+/// Read the commits in a range from the local history, newest first
+pub fn read_local_commits(repo_ref: &str, range: &CommitRange) -> Result<Vec<CommitInfo>, AppError> {
+    let path = resolve_path(repo_ref);
+    if !path.is_dir() {
+        return Err(AppError::not_found(format!("Local repository not found: {}", path.display())));
+    }
+    
+    // Unit/record separators keep subjects with any punctuation intact
+    let mut args = vec![
+        "log".to_string(),
+        format!("--max-count={}", range.max_commits()),
+        "--format=%H%x1f%s%x1f%an%x1f%ae%x1f%aI%x1e".to_string(),
+    ];
+    let (since, until) = range.window()?;
+    args.extend(since.map(|d| format!("--since={}", d.to_rfc3339())));
+    args.extend(until.map(|d| format!("--until={}", d.to_rfc3339())));
+    
+    let head = range.head.as_deref().unwrap_or("HEAD");
+    args.push("--end-of-options".to_string());
+    args.push(match &range.base {
+        Some(base) => format!("{}..{}", base, head),
+        None => head.to_string(),
+    });
+    
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let log = git(&path, &args).ok_or_else(|| AppError::io(format!("Failed to read git history in {}", path.display())))?;
+    
+    Ok(log
+        .split('\u{1e}')
+        .filter_map(|record| {
+            let fields: Vec<&str> = record.trim().split('\u{1f}').collect();
+            if fields.len() != 5 {
+                return None;
+            }
+            Some(CommitInfo {
+                sha: fields[0].chars().take(7).collect(),
+                message: fields[1].to_string(),
+                author_name: fields[2].to_string(),
+                author_email: fields[3].to_string(),
+                date: normalize_date(fields[4]).unwrap_or_else(|| fields[4].to_string()),
+                author_login: None,
+            })
+        })
+        .collect())
+}
+
+/// Commit authors ranked by non-merge commits on the checked out branch
+pub fn read_local_contributors(repo_ref: &str, limit: u32) -> Result<Vec<ContributorInfo>, AppError> {
+    let path = resolve_path(repo_ref);
+    let summary = git(&path, &["shortlog", "-sn", "--no-merges", "HEAD"])
+        .ok_or_else(|| AppError::io(format!("Failed to read git history in {}", path.display())))?;
+    
+    Ok(summary
+        .lines()
+        .filter_map(|line| {
+            let (count, name) = line.trim().split_once('\t')?;
+            Some(ContributorInfo {
+                login: name.trim().to_string(),
+                avatar_url: String::new(),
+                html_url: String::new(),
+                contributions: count.trim().parse().ok()?,
+            })
+        })
+        .take(limit as usize)
+        .collect())
+}
+
+/// The checkout's `.repocard.toml`, or `None` when it has none
+pub fn read_local_project_config(repo_ref: &str) -> Result<Option<ProjectConfig>, AppError> {
+    let path = resolve_path(repo_ref);
+    let root = git(&path, &["rev-parse", "--show-toplevel"])
+        .map(PathBuf::from)
+        .unwrap_or(path);
+    
+    match fs::read_to_string(root.join(PROJECT_CONFIG_FILE)) {
+        Ok(source) => ProjectConfig::parse(&source).map(Some),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(AppError::io(format!("Failed to read {}: {}", PROJECT_CONFIG_FILE, e))),
+    }
+}
+
+/// Read tags from the local checkout, most recently created first
+pub fn read_local_tags(repo_ref: &str, limit: u32) -> Result<Vec<TagInfo>, AppError> {
+    let path = resolve_path(repo_ref);
+    if !path.is_dir() {
+        return Err(AppError::not_found(format!("Local repository not found: {}", path.display())));
+    }
+    
+    // `*objectname` is the tagged commit of an annotated tag and empty for lightweight ones
+    let count = format!("--count={}", limit.max(1));
+    let refs = git(
+        &path,
+        &["for-each-ref", "--sort=-creatordate", &count, "--format=%(refname:short)%1f%(*objectname)%1f%(objectname)", "refs/tags"],
+    )
+    .ok_or_else(|| AppError::io(format!("Failed to read tags in {}", path.display())))?;
+    
+    Ok(refs
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\u{1f}');
+            let name = fields.next()?.to_string();
+            let peeled = fields.next().unwrap_or_default();
+            let object = fields.next().unwrap_or_default();
+            let sha = if peeled.is_empty() { object } else { peeled };
+            Some(TagInfo {
+                name,
+                sha: sha.to_string(),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git_available() -> bool {
+        Command::new("git").arg("--version").output().is_ok()
+    }
+
+    #[test]
+    fn test_metadata_from_manifest_without_git() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\ndescription = \"A demo crate\"\nlicense = \"Apache-2.0\"\nkeywords = [\"cli\", \"demo\"]\n",
+        )
+        .unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() { println!(\"hi\"); }").unwrap();
+        fs::write(dir.path().join("build.sh"), "echo hi").unwrap();
+        
+        let metadata = read_local_metadata(&dir.path().to_string_lossy()).unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("A demo crate"));
+        assert_eq!(metadata.language.as_deref(), Some("Rust"));
+        let languages: Vec<&str> = metadata.languages.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(languages, ["Rust", "Shell"]);
+        assert_eq!(metadata.languages[1].bytes, 7);
+        assert_eq!(metadata.topics, vec!["cli", "demo"]);
+        assert_eq!(metadata.license.unwrap().spdx_id.as_deref(), Some("Apache-2.0"));
+        assert!(metadata.updated_at.len() >= 10);
+    }
+
+    #[test]
+    fn test_readme_description_skips_headings_and_badges() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("README.md"),
+            "# Title\n\n[![CI](badge.svg)](ci)\n\nFast card generator\nfor repositories.\n\n## Usage\n",
+        )
+        .unwrap();
+        assert_eq!(
+            readme_description(dir.path()).as_deref(),
+            Some("Fast card generator for repositories.")
+        );
+    }
+
+    #[test]
+    fn test_detect_license_text() {
+        let mit = "MIT License\n\nPermission is hereby granted, free of charge, to any person";
+        assert_eq!(detect_license_text(mit).map(|l| l.0), Some("MIT"));
+        let apache = "Apache License\nVersion 2.0, January 2004";
+        assert_eq!(detect_license_text(apache).map(|l| l.0), Some("Apache-2.0"));
+        assert_eq!(detect_license_text("All rights reserved"), None);
+    }
+
+    #[test]
+    fn test_is_local_path() {
+        let dir = TempDir::new().unwrap();
+        assert!(is_local_path(&dir.path().to_string_lossy()));
+        assert!(!is_local_path("owner/repo"));
+        assert!(!is_local_path("https://github.com/owner/repo"));
+    }
+
+    #[test]
+    fn test_missing_checkout_is_not_found() {
+        let dir = TempDir::new().unwrap();
+        let missing = dir.path().join("missing").to_string_lossy().to_string();
+        assert_eq!(read_local_metadata(&missing).unwrap_err().kind(), "not_found");
+        assert_eq!(read_local_commits(&missing, &CommitRange::latest(5)).unwrap_err().kind(), "not_found");
+    }
+
+    #[test]
+    fn test_commits_from_git_history() {
+        if !git_available() {
+            return;
+        }
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        let run = |args: &[&str]| {
+            let status = Command::new("git").arg("-C").arg(repo).args(args).output().unwrap().status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        run(&["init", "-q"]);
+        run(&["config", "user.name", "Test"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "commit.gpgsign", "false"]);
+        fs::write(repo.join("lib.rs"), "pub fn demo() {}").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "feat: first | with punctuation"]);
+        
+        let commits = read_local_commits(&repo.to_string_lossy(), &CommitRange::latest(10)).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].message, "feat: first | with punctuation");
+        assert_eq!(commits[0].author_name, "Test");
+        assert_eq!(commits[0].sha.len(), 7);
+        
+        run(&["tag", "v1.0.0"]);
+        for message in ["fix: second", "feat: third"] {
+            run(&["commit", "-q", "--allow-empty", "-m", message]);
+        }
+        let range = CommitRange::parse("v1.0.0..HEAD").unwrap();
+        let commits = read_local_commits(&repo.to_string_lossy(), &range).unwrap();
+        let messages: Vec<&str> = commits.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(messages, vec!["feat: third", "fix: second"]);
+        
+        run(&["tag", "-a", "v1.1.0", "-m", "Release 1.1.0"]);
+        let tags = read_local_tags(&repo.to_string_lossy(), 10).unwrap();
+        let names: Vec<&str> = tags.iter().map(|t| t.name.as_str()).collect();
+        assert!(names.contains(&"v1.0.0") && names.contains(&"v1.1.0"));
+        assert!(tags.iter().all(|t| t.sha.len() == 40));
+        let head = Command::new("git").arg("-C").arg(repo).args(["rev-parse", "HEAD"]).output().unwrap();
+        let head = String::from_utf8_lossy(&head.stdout).trim().to_string();
+        assert_eq!(tags.iter().find(|t| t.name == "v1.1.0").unwrap().sha, head);
+        
+        let metadata = read_local_metadata(&repo.to_string_lossy()).unwrap();
+        assert_eq!(metadata.owner.login, "Test");
+        assert_eq!(metadata.language.as_deref(), Some("Rust"));
+        
+        run(&["remote", "add", "origin", "git@github.com:acme/demo.git"]);
+        let metadata = read_local_metadata(&repo.to_string_lossy()).unwrap();
+        assert_eq!(metadata.full_name, "acme/demo");
+        assert_eq!(metadata.latest_release.as_deref(), Some("v1.1.0"));
+        assert_eq!(metadata.contributors.len(), 1);
+        assert_eq!(metadata.contributors[0].login, "Test");
+        assert_eq!(metadata.contributors[0].contributions, 3);
+        assert_eq!(metadata.html_url, "https://github.com/acme/demo");
+    }
+}
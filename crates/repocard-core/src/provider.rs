@@ -0,0 +1,1310 @@
+// RepoCard Studio - Repository Provider Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::avatar::AvatarCache;
+use crate::cache::{CacheEntry, ResponseCache, API_CACHE_TTL_SECS, AVATAR_CACHE_TTL_SECS};
+use crate::config::{ProjectConfig, PROJECT_CONFIG_FILE};
+use crate::error::AppError;
+use crate::gitea::GiteaClient;
+use crate::github::GitHubClient;
+use crate::gitlab::GitLabClient;
+use crate::local;
+use crate::repo_ref::RepoRef;
+use crate::{CommitInfo, CommitRange, ContributorInfo, RateLimitStatus, ReleaseInfo, RepoMetadata, TagInfo};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, ETAG, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub(crate) const USER_AGENT: &str = "RepoCard-Studio/1.0 (kindware.dev)";
+const CONFIG_DIR_NAME: &str = "repocard-studio";
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Retries for transient failures (5xx, secondary rate limits, dropped connections)
+const MAX_RETRIES: u32 = 3;
+const BASE_BACKOFF_MS: u64 = 500;
+/// Waits longer than this are reported to the caller instead of slept through
+const MAX_WAIT_SECS: u64 = 30;
+/// Upper bound on commits collected for one range, so an open-ended range cannot page forever
+pub const MAX_RANGE_COMMITS: usize = 10_000;
+/// Releases or tags inspected when looking for the previous version
+const TAG_SCAN_LIMIT: u32 = 100;
+/// Contributors attached to repository metadata; enough to spot first-time contributors
+pub const CONTRIBUTOR_LIMIT: u32 = 100;
+/// Response headers kept with cached bodies; the rest are not needed to replay them
+const CACHED_HEADERS: [&str; 5] = ["content-type", "link", "x-next-page", "x-total", "x-total-pages"];
+
+/// Forge hosting a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    GitHub,
+    GitLab,
+    /// Gitea and its Forgejo fork, including Codeberg
+    Gitea,
+}
+
+impl ProviderKind {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "GitHub",
+            ProviderKind::GitLab => "GitLab",
+            ProviderKind::Gitea => "Gitea",
+        }
+    }
+
+    fn default_host(self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "github.com",
+            ProviderKind::GitLab => "gitlab.com",
+            ProviderKind::Gitea => "codeberg.org",
+        }
+    }
+
+    /// REST API root for a host; public GitHub is the only one on a separate domain
+    fn api_base(self, scheme: &str, host: &str) -> String {
+        match self {
+            ProviderKind::GitHub if host == "github.com" => "https://api.github.com".to_string(),
+            ProviderKind::GitHub => format!("{}://{}/api/v3", scheme, host),
+            ProviderKind::GitLab => format!("{}://{}/api/v4", scheme, host),
+            ProviderKind::Gitea => format!("{}://{}/api/v1", scheme, host),
+        }
+    }
+
+    /// Variables naming the server and API root (GitHub Actions, GitLab CI;
+    /// Gitea has no standard pair, so ours are app-specific)
+    fn server_env_vars(self) -> (&'static str, &'static str) {
+        match self {
+            ProviderKind::GitHub => ("GITHUB_SERVER_URL", "GITHUB_API_URL"),
+            ProviderKind::GitLab => ("CI_SERVER_URL", "CI_API_V4_URL"),
+            ProviderKind::Gitea => ("GITEA_SERVER_URL", "GITEA_API_URL"),
+        }
+    }
+
+    fn token_env_vars(self) -> &'static [&'static str] {
+        match self {
+            ProviderKind::GitHub => &["GITHUB_TOKEN", "GH_TOKEN"],
+            ProviderKind::GitLab => &["GITLAB_TOKEN", "GL_TOKEN"],
+            ProviderKind::Gitea => &["GITEA_TOKEN", "FORGEJO_TOKEN"],
+        }
+    }
+
+    fn token_file_name(self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "github-token",
+            ProviderKind::GitLab => "gitlab-token",
+            ProviderKind::Gitea => "gitea-token",
+        }
+    }
+}
+
+/// Personal access token for a forge API.
+///
+/// Deliberately not `Serialize` and redacted in `Debug` so it can never
+/// end up in logs, error strings or exported files.
+#[derive(Clone)]
+pub struct AccessToken(String);
+
+impl AccessToken {
+    /// Wrap a raw token, ignoring blank values
+    pub fn new(token: impl Into<String>) -> Option<Self> {
+        let token = token.into().trim().to_string();
+        if token.is_empty() {
+            None
+        } else {
+            Some(Self(token))
+        }
+    }
+
+    /// Resolve a token from an explicit value, then the provider's environment
+    /// variables (e.g. `GITHUB_TOKEN`/`GH_TOKEN`), then the user config file
+    pub fn resolve(kind: ProviderKind, explicit: Option<String>) -> Option<Self> {
+        explicit
+            .and_then(Self::new)
+            .or_else(|| {
+                kind.token_env_vars()
+                    .iter()
+                    .find_map(|var| std::env::var(var).ok().and_then(Self::new))
+            })
+            .or_else(|| {
+                token_file_path(kind)
+                    .and_then(|path| fs::read_to_string(path).ok())
+                    .and_then(Self::new)
+            })
+    }
+
+    /// Strip any occurrence of the token from a message
+    pub fn redact(&self, message: &str) -> String {
+        message.replace(&self.0, "***")
+    }
+
+    fn bearer_header(&self) -> Option<HeaderValue> {
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", self.0)).ok()?;
+        value.set_sensitive(true);
+        Some(value)
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AccessToken(***)")
+    }
+}
+
+/// Location of the optional token file, e.g. `<config dir>/repocard-studio/github-token`
+pub fn token_file_path(kind: ProviderKind) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(kind.token_file_name()))
+}
+
+/// Remove the token from an error message before it leaves the backend
+pub(crate) fn redacted(message: String, token: Option<&AccessToken>) -> String {
+    match token {
+        Some(token) => token.redact(&message),
+        None => message,
+    }
+}
+
+/// Split a `https://host/path` URL (scheme optional) into its lowercased host and path
+pub(crate) fn split_host(url: &str) -> Option<(String, &str)> {
+    let rest = match url.split_once("://") {
+        Some(("http" | "https", rest)) => rest,
+        Some(_) => return None,
+        None => url,
+    };
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    if host.is_empty() || !host.contains('.') {
+        return None;
+    }
+    Some((host.to_lowercase(), path))
+}
+
+/// Server a provider client talks to and how
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Web host repository URLs are copied from, e.g. `github.com` or `gitlab.example.com`
+    pub host: String,
+    /// REST API root, e.g. `https://api.github.com` or `https://gitlab.com/api/v4`
+    pub api_base: String,
+    pub timeout: Duration,
+    /// Explicit proxy URL; when unset the standard `HTTPS_PROXY` variables apply
+    pub proxy: Option<String>,
+    /// Where GET responses are kept for conditional requests and offline use; `None` disables caching
+    pub cache: Option<ResponseCache>,
+}
+
+impl ServerConfig {
+    /// Configuration for the provider's public instance (github.com, gitlab.com)
+    pub fn default_for(kind: ProviderKind) -> Self {
+        let host = kind.default_host();
+        Self {
+            host: host.to_string(),
+            api_base: kind.api_base("https", host),
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            proxy: None,
+            cache: ResponseCache::in_data_dir("api", Duration::from_secs(API_CACHE_TTL_SECS)),
+        }
+    }
+
+    /// Configuration for a server given by its web URL or bare hostname,
+    /// e.g. a GitHub Enterprise Server or self-managed GitLab instance
+    pub fn for_server(kind: ProviderKind, server_url: &str) -> Result<Self, AppError> {
+        let server_url = server_url.trim().trim_end_matches('/');
+        let scheme = match server_url.split_once("://") {
+            Some((scheme, _)) => scheme,
+            None => "https",
+        };
+        let (host, _) = split_host(server_url)
+            .ok_or_else(|| AppError::parse(format!("Invalid {} server URL: {}", kind.display_name(), server_url)))?;
+        let host = match host.as_str() {
+            "www.github.com" | "api.github.com" => "github.com".to_string(),
+            _ => host,
+        };
+        
+        Ok(Self {
+            api_base: kind.api_base(scheme, &host),
+            host,
+            ..Self::default_for(kind)
+        })
+    }
+
+    /// Configuration from the CI server variables (`GITHUB_SERVER_URL`/`GITHUB_API_URL`
+    /// on GitHub Actions, `CI_SERVER_URL`/`CI_API_V4_URL` on GitLab CI), falling
+    /// back to the public instance
+    pub fn from_env(kind: ProviderKind) -> Self {
+        let (server_var, api_var) = kind.server_env_vars();
+        
+        let mut config = std::env::var(server_var)
+            .ok()
+            .and_then(|url| Self::for_server(kind, &url).ok())
+            .unwrap_or_else(|| Self::default_for(kind));
+        if let Some(api) = std::env::var(api_var).ok().filter(|a| !a.trim().is_empty()) {
+            config.api_base = api.trim().trim_end_matches('/').to_string();
+        }
+        config
+    }
+}
+
+/// HTTP plumbing shared by every provider client: pooled connections, auth,
+/// retries with backoff and rate limit tracking.
+///
+/// Cheap to clone; clones share the connection pool and rate limit state.
+#[derive(Debug, Clone)]
+pub(crate) struct ApiClient {
+    http: Client,
+    kind: ProviderKind,
+    api_base: String,
+    /// Rate limit headers from the most recent response
+    rate_limit: Arc<Mutex<Option<RateLimitStatus>>>,
+    cache: Option<ResponseCache>,
+    /// Revalidate cached responses even while they are fresh
+    refresh: bool,
+}
+
+impl ApiClient {
+    pub(crate) fn new(kind: ProviderKind, config: &ServerConfig, accept: &'static str) -> Result<Self, AppError> {
+        let mut headers = HeaderMap::new();
+        headers.insert("Accept", HeaderValue::from_static(accept));
+        
+        let mut builder = Client::builder()
+            .user_agent(USER_AGENT)
+            .default_headers(headers)
+            .timeout(config.timeout)
+            .connect_timeout(Duration::from_secs(CONNECT_TIMEOUT_SECS))
+            .pool_idle_timeout(Duration::from_secs(90));
+        
+        if let Some(proxy) = &config.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| AppError::parse(format!("Invalid proxy URL: {}", e)))?;
+            builder = builder.proxy(proxy);
+        }
+        
+        let http = builder
+            .build()
+            .map_err(|e| AppError::network(format!("Failed to create HTTP client: {}", e)))?;
+        
+        Ok(Self {
+            http,
+            kind,
+            api_base: config.api_base.trim_end_matches('/').to_string(),
+            rate_limit: Arc::new(Mutex::new(None)),
+            cache: config.cache.clone(),
+            refresh: false,
+        })
+    }
+
+    /// A client sharing this one's connections that revalidates every cached response
+    pub(crate) fn refreshing(&self) -> Self {
+        Self {
+            refresh: true,
+            ..self.clone()
+        }
+    }
+
+    /// Rate limit state reported by the most recent response, if any
+    pub(crate) fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        self.rate_limit.lock().ok().and_then(|last| last.clone())
+    }
+
+    fn record_rate_limit(&self, status: RateLimitStatus) {
+        if let Ok(mut last) = self.rate_limit.lock() {
+            *last = Some(status);
+        }
+    }
+
+    pub(crate) fn api_url(&self, path: &str) -> String {
+        format!("{}/{}", self.api_base, path.trim_start_matches('/'))
+    }
+
+    /// Build an API request, attaching the bearer token when present
+    pub(crate) fn get(&self, path: &str, token: Option<&AccessToken>) -> RequestBuilder {
+        let request = self.http.get(self.api_url(path));
+        
+        match token.and_then(AccessToken::bearer_header) {
+            Some(value) => request.header(AUTHORIZATION, value),
+            None => request,
+        }
+    }
+
+    /// Send a request, retrying transient failures with exponential backoff
+    pub(crate) async fn send_with_retry(&self, request: RequestBuilder, token: Option<&AccessToken>) -> Result<Response, AppError> {
+        let mut attempt = 0;
+        
+        loop {
+            let current = request
+                .try_clone()
+                .ok_or_else(|| AppError::network("Request cannot be retried"))?;
+            
+            match current.send().await {
+                Ok(response) => {
+                    let now = Utc::now().timestamp();
+                    let limits = RateLimitHeaders::from_headers(response.headers());
+                    if !limits.is_empty() {
+                        self.record_rate_limit(limits.to_status(false, now));
+                    }
+                    
+                    match retry_delay(response.status(), &limits, attempt, now) {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Ok(response),
+                    }
+                }
+                Err(e) if attempt < MAX_RETRIES && (e.is_timeout() || e.is_connect()) => {
+                    tokio::time::sleep(backoff_delay(attempt)).await;
+                }
+                Err(e) => return Err(AppError::network(redacted(format!("Network error: {}", e), token))),
+            }
+            
+            attempt += 1;
+        }
+    }
+
+    /// Turn a non-success response into a redacted error, recognising rate limits
+    pub(crate) async fn ensure_success(&self, response: Response, token: Option<&AccessToken>) -> Result<Response, AppError> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        
+        let status = response.status();
+        let limits = RateLimitHeaders::from_headers(response.headers());
+        let body = response.text().await.unwrap_or_default();
+        let provider = self.kind.display_name();
+        
+        if limits.is_rate_limited(status, &body) {
+            let rate_limit = limits.to_status(true, Utc::now().timestamp());
+            let message = match &rate_limit.resets_at {
+                Some(resets_at) => format!("{} API rate limit exceeded; rate limited until {}", provider, resets_at),
+                None => format!("{} API rate limit exceeded; try again later", provider),
+            };
+            let resets_at = rate_limit.resets_at.clone();
+            self.record_rate_limit(rate_limit);
+            return Err(AppError::rate_limited(message, resets_at));
+        }
+        
+        let message = redacted(format!("{} API error ({}): {}", provider, status, body), token);
+        Err(match status {
+            StatusCode::NOT_FOUND | StatusCode::GONE => AppError::not_found(message),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => AppError::unauthorized(message),
+            _ => AppError::network(message),
+        })
+    }
+
+    /// Send a request and turn transport or HTTP failures into redacted errors.
+    ///
+    /// GET responses go through the response cache: fresh entries are served
+    /// without a request, stale ones are revalidated with `If-None-Match` (a 304
+    /// does not count against GitHub's rate limit), and when the server cannot
+    /// be reached or is rate limiting, the last good copy is served instead.
+    pub(crate) async fn send_checked(&self, request: RequestBuilder, token: Option<&AccessToken>) -> Result<Response, AppError> {
+        let url = request
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .filter(|r| r.method() == reqwest::Method::GET)
+            .map(|r| r.url().to_string());
+        let (Some(cache), Some(url)) = (&self.cache, url) else {
+            let response = self.send_with_retry(request, token).await?;
+            return self.ensure_success(response, token).await;
+        };
+        
+        let key = ResponseCache::key(&url, token.map(|t| t.0.as_str()));
+        let cached = cache.load(&key);
+        if let Some(entry) = cached.as_ref().filter(|e| !self.refresh && cache.is_fresh(e)) {
+            return Ok(cached_response(entry));
+        }
+        
+        let unconditional = request.try_clone().and_then(without_if_none_match);
+        let request = match cached.as_ref().and_then(|e| e.etag.as_deref()) {
+            Some(etag) => request.header(IF_NONE_MATCH, etag),
+            None => request,
+        };
+        let mut response = match self.send_with_retry(request, token).await {
+            Ok(response) => response,
+            Err(e) => return cached.map(|entry| cached_response(&entry)).ok_or(e),
+        };
+        
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = cached {
+                cache.touch(&key, &mut entry);
+                return Ok(cached_response(&entry));
+            }
+            // Nothing cached to stand for the body, so ask for it in full
+            if let Some(unconditional) = unconditional {
+                response = self.send_with_retry(unconditional, token).await?;
+            }
+        }
+        
+        let response = match self.ensure_success(response, token).await {
+            Ok(response) => response,
+            Err(e @ (AppError::RateLimited { .. } | AppError::Network { .. })) => {
+                return cached.map(|entry| cached_response(&entry)).ok_or(e);
+            }
+            Err(e) => return Err(e),
+        };
+        
+        let header = |name: &str| response.headers().get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+        let etag = header(ETAG.as_str());
+        let headers = CACHED_HEADERS
+            .iter()
+            .filter_map(|name| header(name).map(|value| (name.to_string(), value)))
+            .collect();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| AppError::network(redacted(format!("Network error: {}", e), token)))?;
+        
+        let entry = CacheEntry {
+            url,
+            etag,
+            fetched_at: Utc::now().timestamp(),
+            headers,
+            body: body.to_vec(),
+        };
+        // A cache that cannot be written only costs a refetch next time
+        let _ = cache.store(&key, &entry);
+        Ok(cached_response(&entry))
+    }
+
+    /// Body of a file-like resource as text, or `None` when the server has no such resource
+    pub(crate) async fn get_optional_text(&self, request: RequestBuilder, token: Option<&AccessToken>) -> Result<Option<String>, AppError> {
+        match self.send_checked(request, token).await {
+            Ok(response) => response
+                .text()
+                .await
+                .map(Some)
+                .map_err(|e| AppError::network(redacted(format!("Network error: {}", e), token))),
+            Err(AppError::NotFound { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Collect up to `limit` items from a paginated list endpoint.
+    /// `request` builds the request for a 1-based page number; a short page ends the listing.
+    pub(crate) async fn get_paginated<T: DeserializeOwned>(
+        &self,
+        page_size: usize,
+        limit: usize,
+        token: Option<&AccessToken>,
+        request: impl Fn(u32) -> RequestBuilder,
+    ) -> Result<Vec<T>, AppError> {
+        let mut items = Vec::new();
+        let mut page = 1;
+        
+        while items.len() < limit {
+            let response = self.send_checked(request(page), token).await?;
+            let batch: Vec<T> = response
+                .json()
+                .await
+                .map_err(|e| AppError::parse(format!("Failed to parse {} response: {}", self.kind.display_name(), e)))?;
+            
+            let last_page = batch.len() < page_size;
+            items.extend(batch);
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+        
+        items.truncate(limit);
+        Ok(items)
+    }
+}
+
+/// The request without `If-None-Match`, so the server answers with the full body
+fn without_if_none_match(request: RequestBuilder) -> Option<RequestBuilder> {
+    let (client, request) = request.build_split();
+    let mut request = request.ok()?;
+    request.headers_mut().remove(IF_NONE_MATCH);
+    Some(RequestBuilder::from_parts(client, request))
+}
+
+/// Replay a cached body as a successful response
+fn cached_response(entry: &CacheEntry) -> Response {
+    let mut builder = http::Response::builder().status(StatusCode::OK);
+    for (name, value) in &entry.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    let response = builder
+        .body(entry.body.clone())
+        .unwrap_or_else(|_| http::Response::new(entry.body.clone()));
+    Response::from(response)
+}
+
+/// Inclusive `since`/`until` bounds of a commit range
+pub(crate) type DateWindow = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+impl CommitRange {
+    /// The most recent `count` commits on the default branch
+    pub fn latest(count: u32) -> Self {
+        Self {
+            limit: Some(count),
+            ..Self::default()
+        }
+    }
+
+    /// Parse `base..head`, `base...head` or a single branch, tag or SHA
+    pub fn parse(spec: &str) -> Result<Self, AppError> {
+        let spec = spec.trim();
+        let non_empty = |s: &str| (!s.trim().is_empty()).then(|| s.trim().to_string());
+        
+        let (base, head) = match spec.split_once("..") {
+            Some((base, head)) => (non_empty(base), non_empty(head.trim_start_matches('.'))),
+            None => (None, non_empty(spec)),
+        };
+        if base.is_none() && head.is_none() {
+            return Err(AppError::parse(format!("Invalid commit range: {}", spec)));
+        }
+        
+        Ok(Self {
+            head,
+            base,
+            ..Self::default()
+        })
+    }
+
+    /// Most commits to collect for this range
+    pub(crate) fn max_commits(&self) -> usize {
+        self.limit
+            .map(|limit| limit.max(1) as usize)
+            .unwrap_or(MAX_RANGE_COMMITS)
+            .min(MAX_RANGE_COMMITS)
+    }
+
+    /// The since/until window in UTC; bare dates cover the whole day
+    pub(crate) fn window(&self) -> Result<DateWindow, AppError> {
+        let since = self.since.as_deref().map(|d| parse_range_date(d, false)).transpose()?;
+        let until = self.until.as_deref().map(|d| parse_range_date(d, true)).transpose()?;
+        Ok((since, until))
+    }
+
+    /// Whether a commit date falls inside the since/until window
+    pub(crate) fn includes_date(&self, date: &str) -> bool {
+        let Ok((since, until)) = self.window() else {
+            return true;
+        };
+        let Ok(date) = DateTime::parse_from_rfc3339(date) else {
+            return true;
+        };
+        since.is_none_or(|since| date >= since) && until.is_none_or(|until| date <= until)
+    }
+}
+
+/// RFC 3339 timestamp, or a `YYYY-MM-DD` date at the start or end of that day (UTC)
+fn parse_range_date(date: &str, end_of_day: bool) -> Result<DateTime<Utc>, AppError> {
+    let date = date.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(date) {
+        return Ok(parsed.with_timezone(&Utc));
+    }
+    
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| AppError::parse(format!("Invalid date: {}", date)))?;
+    let time = if end_of_day {
+        day.and_hms_opt(23, 59, 59)
+    } else {
+        day.and_hms_opt(0, 0, 0)
+    };
+    time.map(|t| t.and_utc()).ok_or_else(|| AppError::parse(format!("Invalid date: {}", date)))
+}
+
+/// Numeric components of a version-like tag (`v1.10.0` sorts after `v1.9.2`)
+fn version_key(tag: &str) -> Vec<u64> {
+    tag.split(|c: char| !c.is_ascii_digit())
+        .filter(|part| !part.is_empty())
+        .filter_map(|part| part.parse().ok())
+        .collect()
+}
+
+/// The tag after `current` in a newest-first list, or the newest tag when `current` is absent
+fn previous_tag(newest_first: &[String], current: Option<&str>) -> Option<String> {
+    let current = current.map(str::trim).filter(|c| !c.is_empty());
+    match current.and_then(|c| newest_first.iter().position(|t| t == c)) {
+        Some(index) => newest_first.get(index + 1).cloned(),
+        None => newest_first.iter().find(|t| Some(t.as_str()) != current).cloned(),
+    }
+}
+
+/// Format a range bound the way the forge APIs expect it
+pub(crate) fn format_range_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Rate limit headers as sent by GitHub (`X-RateLimit-*`) or GitLab (`RateLimit-*`)
+#[derive(Debug, Clone, Default, PartialEq)]
+struct RateLimitHeaders {
+    limit: Option<u32>,
+    remaining: Option<u32>,
+    reset_epoch: Option<i64>,
+    retry_after_secs: Option<u64>,
+}
+
+impl RateLimitHeaders {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        fn parse<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            [format!("x-{}", name), name.to_string()]
+                .iter()
+                .find_map(|name| headers.get(name.as_str())?.to_str().ok()?.trim().parse().ok())
+        }
+        
+        Self {
+            limit: parse(headers, "ratelimit-limit"),
+            remaining: parse(headers, "ratelimit-remaining"),
+            reset_epoch: parse(headers, "ratelimit-reset"),
+            retry_after_secs: headers
+                .get(RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok()),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether a 403/429 response was caused by a primary or secondary rate limit
+    fn is_rate_limited(&self, status: StatusCode, body: &str) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS
+            || (status == StatusCode::FORBIDDEN
+                && (self.remaining == Some(0)
+                    || self.retry_after_secs.is_some()
+                    || body.to_lowercase().contains("rate limit")))
+    }
+
+    /// Seconds to wait before the limit lifts, preferring `Retry-After`
+    fn wait_secs(&self, now: i64) -> Option<u64> {
+        self.retry_after_secs.or_else(|| {
+            if self.remaining == Some(0) {
+                self.reset_epoch.map(|reset| reset.saturating_sub(now).max(0) as u64)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn to_status(&self, limited: bool, now: i64) -> RateLimitStatus {
+        let resets_at = match (limited, self.retry_after_secs) {
+            (true, Some(secs)) => Some(now.saturating_add(secs as i64)),
+            _ => self.reset_epoch,
+        };
+        
+        RateLimitStatus {
+            limit: self.limit,
+            remaining: self.remaining,
+            resets_at: resets_at.and_then(format_epoch),
+            retry_after_secs: if limited { self.wait_secs(now) } else { None },
+            limited,
+        }
+    }
+}
+
+fn format_epoch(epoch: i64) -> Option<String> {
+    DateTime::<Utc>::from_timestamp(epoch, 0).map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+/// Exponential backoff: 0.5s, 1s, 2s, ...
+fn backoff_delay(attempt: u32) -> Duration {
+    Duration::from_millis(BASE_BACKOFF_MS.saturating_mul(1 << attempt.min(10)))
+}
+
+/// How long to wait before retrying a response, or `None` if it should not be retried
+fn retry_delay(status: StatusCode, limits: &RateLimitHeaders, attempt: u32, now: i64) -> Option<Duration> {
+    if attempt >= MAX_RETRIES {
+        return None;
+    }
+    
+    if status.is_server_error() {
+        return Some(backoff_delay(attempt));
+    }
+    
+    if status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS {
+        return limits
+            .wait_secs(now)
+            .filter(|secs| *secs <= MAX_WAIT_SECS)
+            .map(|secs| Duration::from_secs(secs).max(backoff_delay(attempt)));
+    }
+    
+    None
+}
+
+/// Routes repository requests to the client for the forge hosting them
+#[derive(Debug, Clone)]
+pub struct RepoProvider {
+    pub github: GitHubClient,
+    pub gitlab: GitLabClient,
+    pub gitea: GiteaClient,
+    /// Owner avatars already embedded, shared across server reconfiguration
+    pub avatars: AvatarCache,
+}
+
+/// Run filesystem and git work off the async runtime's worker threads
+async fn run_blocking<T, F>(work: F) -> Result<T, AppError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, AppError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|e| AppError::io(format!("Local repository task failed: {}", e)))?
+}
+
+impl RepoProvider {
+    /// Clients for the public instances, or the CI server when running in a pipeline
+    pub fn from_env() -> Result<Self, AppError> {
+        Ok(Self {
+            github: GitHubClient::new(ServerConfig::from_env(ProviderKind::GitHub))?,
+            gitlab: GitLabClient::new(ServerConfig::from_env(ProviderKind::GitLab))?,
+            gitea: GiteaClient::new(ServerConfig::from_env(ProviderKind::Gitea))?,
+            avatars: AvatarCache::new(ResponseCache::in_data_dir("avatars", Duration::from_secs(AVATAR_CACHE_TTL_SECS)))?,
+        })
+    }
+
+    /// Replace the client for one provider with a newly configured one
+    pub fn configure(&mut self, kind: ProviderKind, config: ServerConfig) -> Result<(), AppError> {
+        match kind {
+            ProviderKind::GitHub => self.github = GitHubClient::new(config)?,
+            ProviderKind::GitLab => self.gitlab = GitLabClient::new(config)?,
+            ProviderKind::Gitea => self.gitea = GiteaClient::new(config)?,
+        }
+        Ok(())
+    }
+
+    /// A copy that revalidates cached responses and avatars instead of trusting their TTL
+    pub fn refreshing(&self) -> Self {
+        Self {
+            github: self.github.refreshing(),
+            gitlab: self.gitlab.refreshing(),
+            gitea: self.gitea.refreshing(),
+            avatars: self.avatars.refreshing(),
+        }
+    }
+
+    /// Which provider handles a repository URL.
+    /// Bare `owner/repo` references default to GitHub.
+    pub fn kind_for(&self, repo_url: &str) -> ProviderKind {
+        match RepoRef::parse(repo_url).map(|r| r.host) {
+            Ok(host) if self.gitlab.handles_host(&host) => ProviderKind::GitLab,
+            Ok(host) if self.gitea.handles_host(&host) => ProviderKind::Gitea,
+            _ => ProviderKind::GitHub,
+        }
+    }
+
+    /// Fetch repository metadata from a local checkout or whichever forge hosts the repository,
+    /// with the repository's `.repocard.toml` applied
+    pub async fn fetch_repository_metadata(
+        &self,
+        repo_url: &str,
+        token: Option<String>,
+    ) -> Result<RepoMetadata, AppError> {
+        let mut metadata = if local::is_local_path(repo_url) {
+            let path = repo_url.to_string();
+            run_blocking(move || local::read_local_metadata(&path)).await?
+        } else {
+            self.fetch_remote_metadata(repo_url, token.clone()).await?
+        };
+        
+        // A config that cannot be fetched is skipped like the other extras, but a broken one is reported
+        match self.fetch_project_config(repo_url, token).await {
+            Ok(Some(config)) => config.apply_to_metadata(&mut metadata),
+            Ok(None) => {}
+            Err(e @ AppError::Parse { .. }) => return Err(e),
+            Err(_) => {}
+        }
+        Ok(metadata)
+    }
+
+    /// The repository's `.repocard.toml` from a local checkout or the default branch,
+    /// or `None` when it has none
+    pub async fn fetch_project_config(
+        &self,
+        repo_url: &str,
+        token: Option<String>,
+    ) -> Result<Option<ProjectConfig>, AppError> {
+        if local::is_local_path(repo_url) {
+            let path = repo_url.to_string();
+            return run_blocking(move || local::read_local_project_config(&path)).await;
+        }
+        let kind = self.kind_for(repo_url);
+        let token = AccessToken::resolve(kind, token);
+        let source = match kind {
+            ProviderKind::GitHub => self.github.fetch_file(repo_url, PROJECT_CONFIG_FILE, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_file(repo_url, PROJECT_CONFIG_FILE, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_file(repo_url, PROJECT_CONFIG_FILE, token.as_ref()).await,
+        }?;
+        source.map(|source| ProjectConfig::parse(&source)).transpose()
+    }
+
+    async fn fetch_remote_metadata(&self, repo_url: &str, token: Option<String>) -> Result<RepoMetadata, AppError> {
+        let kind = self.kind_for(repo_url);
+        let token = AccessToken::resolve(kind, token);
+        let mut metadata = match kind {
+            ProviderKind::GitHub => self.github.fetch_repository_metadata(repo_url, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_repository_metadata(repo_url, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_repository_metadata(repo_url, token.as_ref()).await,
+        }?;
+        
+        // Releases, contributors and languages only decorate the outputs; failures here are not fatal
+        let latest = match kind {
+            ProviderKind::GitHub => self.github.fetch_latest_release(repo_url, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_latest_release(repo_url, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_latest_release(repo_url, token.as_ref()).await,
+        };
+        metadata.latest_release = latest.ok().flatten().map(|r| r.tag_name);
+        
+        let contributors = match kind {
+            ProviderKind::GitHub => self.github.fetch_contributors(repo_url, CONTRIBUTOR_LIMIT, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_contributors(repo_url, CONTRIBUTOR_LIMIT, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_contributors(repo_url, CONTRIBUTOR_LIMIT, token.as_ref()).await,
+        };
+        metadata.contributors = contributors.unwrap_or_default();
+        
+        // GitLab returns its language shares with the project itself
+        let languages = match kind {
+            ProviderKind::GitHub => self.github.fetch_languages(repo_url, token.as_ref()).await,
+            ProviderKind::GitLab => Ok(std::mem::take(&mut metadata.languages)),
+            ProviderKind::Gitea => self.gitea.fetch_languages(repo_url, token.as_ref()).await,
+        };
+        metadata.languages = languages.unwrap_or_default();
+        
+        // Without the avatar the cards fall back to the owner's initial
+        metadata.owner.avatar_data_uri = self.avatars.data_uri(&metadata.owner.avatar_url).await;
+        Ok(metadata)
+    }
+
+    /// Fetch recent commits from a local checkout or whichever forge hosts the repository
+    pub async fn fetch_recent_commits(
+        &self,
+        repo_url: &str,
+        count: u32,
+        token: Option<String>,
+    ) -> Result<Vec<CommitInfo>, AppError> {
+        self.fetch_commits(repo_url, &CommitRange::latest(count), token).await
+    }
+
+    /// Fetch the commits in a range, newest first, across as many pages as needed.
+    /// Without an explicit head, a branch or tag from a pasted deep link is used.
+    pub async fn fetch_commits(
+        &self,
+        repo_url: &str,
+        range: &CommitRange,
+        token: Option<String>,
+    ) -> Result<Vec<CommitInfo>, AppError> {
+        range.window()?;
+        if local::is_local_path(repo_url) {
+            let path = repo_url.to_string();
+            let range = range.clone();
+            return run_blocking(move || local::read_local_commits(&path, &range)).await;
+        }
+        
+        let mut range = range.clone();
+        if range.head.is_none() {
+            range.head = RepoRef::parse(repo_url).ok().and_then(|r| r.git_ref.or(r.tag));
+        }
+        
+        let kind = self.kind_for(repo_url);
+        let token = AccessToken::resolve(kind, token);
+        match kind {
+            ProviderKind::GitHub => self.github.fetch_commits(repo_url, &range, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_commits(repo_url, &range, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_commits(repo_url, &range, token.as_ref()).await,
+        }
+    }
+
+    /// Fetch up to `limit` releases, newest first. Local checkouts have none.
+    pub async fn fetch_releases(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<String>,
+    ) -> Result<Vec<ReleaseInfo>, AppError> {
+        if local::is_local_path(repo_url) {
+            return Ok(Vec::new());
+        }
+        let kind = self.kind_for(repo_url);
+        let token = AccessToken::resolve(kind, token);
+        match kind {
+            ProviderKind::GitHub => self.github.fetch_releases(repo_url, limit, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_releases(repo_url, limit, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_releases(repo_url, limit, token.as_ref()).await,
+        }
+    }
+
+    /// Fetch up to `limit` top contributors from a local checkout or the hosting forge
+    pub async fn fetch_contributors(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<String>,
+    ) -> Result<Vec<ContributorInfo>, AppError> {
+        if local::is_local_path(repo_url) {
+            let path = repo_url.to_string();
+            return run_blocking(move || local::read_local_contributors(&path, limit)).await;
+        }
+        let kind = self.kind_for(repo_url);
+        let token = AccessToken::resolve(kind, token);
+        match kind {
+            ProviderKind::GitHub => self.github.fetch_contributors(repo_url, limit, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_contributors(repo_url, limit, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_contributors(repo_url, limit, token.as_ref()).await,
+        }
+    }
+
+    /// Fetch up to `limit` tags from a local checkout or the hosting forge
+    pub async fn fetch_tags(
+        &self,
+        repo_url: &str,
+        limit: u32,
+        token: Option<String>,
+    ) -> Result<Vec<TagInfo>, AppError> {
+        if local::is_local_path(repo_url) {
+            let path = repo_url.to_string();
+            return run_blocking(move || local::read_local_tags(&path, limit)).await;
+        }
+        let kind = self.kind_for(repo_url);
+        let token = AccessToken::resolve(kind, token);
+        match kind {
+            ProviderKind::GitHub => self.github.fetch_tags(repo_url, limit, token.as_ref()).await,
+            ProviderKind::GitLab => self.gitlab.fetch_tags(repo_url, limit, token.as_ref()).await,
+            ProviderKind::Gitea => self.gitea.fetch_tags(repo_url, limit, token.as_ref()).await,
+        }
+    }
+
+    /// The tag a release of `version` should be compared against: the release
+    /// published before it, or the latest release when `version` is not out yet
+    pub async fn fetch_previous_tag(
+        &self,
+        repo_url: &str,
+        version: Option<&str>,
+        token: Option<String>,
+    ) -> Result<Option<String>, AppError> {
+        let releases = self.fetch_releases(repo_url, TAG_SCAN_LIMIT, token.clone()).await?;
+        let mut tags: Vec<String> = releases
+            .into_iter()
+            .filter(|r| !r.draft && !r.prerelease)
+            .map(|r| r.tag_name)
+            .collect();
+        
+        // Projects that only tag have no release order, so sort the tags by version
+        if tags.is_empty() {
+            tags = self
+                .fetch_tags(repo_url, TAG_SCAN_LIMIT, token)
+                .await?
+                .into_iter()
+                .map(|t| t.name)
+                .collect();
+            tags.sort_by_key(|t| std::cmp::Reverse(version_key(t)));
+        }
+        
+        Ok(previous_tag(&tags, version))
+    }
+
+    /// Rate limit state from the most recent response of the given provider
+    pub fn last_rate_limit(&self, kind: ProviderKind) -> Option<RateLimitStatus> {
+        match kind {
+            ProviderKind::GitHub => self.github.last_rate_limit(),
+            ProviderKind::GitLab => self.gitlab.last_rate_limit(),
+            ProviderKind::Gitea => self.gitea.last_rate_limit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit_headers(pairs: &[(&'static str, &str)]) -> RateLimitHeaders {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        RateLimitHeaders::from_headers(&headers)
+    }
+
+    #[test]
+    fn test_primary_rate_limit_reports_reset_time() {
+        let limits = rate_limit_headers(&[
+            ("x-ratelimit-limit", "60"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1700000600"),
+        ]);
+        assert!(limits.is_rate_limited(StatusCode::FORBIDDEN, ""));
+        
+        let status = limits.to_status(true, 1_700_000_000);
+        assert!(status.limited);
+        assert_eq!(status.retry_after_secs, Some(600));
+        assert_eq!(status.resets_at.as_deref(), Some("2023-11-14T22:23:20Z"));
+        
+        // Ten minutes is too long to sleep through
+        assert_eq!(retry_delay(StatusCode::FORBIDDEN, &limits, 0, 1_700_000_000), None);
+    }
+
+    #[test]
+    fn test_gitlab_rate_limit_headers() {
+        let limits = rate_limit_headers(&[("ratelimit-remaining", "0"), ("ratelimit-reset", "1700000600")]);
+        assert_eq!(limits.remaining, Some(0));
+        assert_eq!(limits.reset_epoch, Some(1_700_000_600));
+        assert!(limits.is_rate_limited(StatusCode::TOO_MANY_REQUESTS, ""));
+    }
+
+    #[test]
+    fn test_secondary_rate_limit_is_retried() {
+        let limits = rate_limit_headers(&[("retry-after", "5")]);
+        assert!(limits.is_rate_limited(StatusCode::FORBIDDEN, ""));
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &limits, 0, 0),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_server_errors_back_off_exponentially() {
+        let limits = RateLimitHeaders::default();
+        assert_eq!(retry_delay(StatusCode::BAD_GATEWAY, &limits, 0, 0), Some(Duration::from_millis(500)));
+        assert_eq!(retry_delay(StatusCode::BAD_GATEWAY, &limits, 2, 0), Some(Duration::from_secs(2)));
+        assert_eq!(retry_delay(StatusCode::BAD_GATEWAY, &limits, MAX_RETRIES, 0), None);
+        assert_eq!(retry_delay(StatusCode::NOT_FOUND, &limits, 0, 0), None);
+    }
+
+    #[test]
+    fn test_plain_forbidden_is_not_rate_limited() {
+        let limits = rate_limit_headers(&[("x-ratelimit-remaining", "42")]);
+        assert!(!limits.is_rate_limited(StatusCode::FORBIDDEN, "Resource not accessible"));
+        assert_eq!(retry_delay(StatusCode::FORBIDDEN, &limits, 0, 0), None);
+    }
+
+    #[test]
+    fn test_token_ignores_blank_values() {
+        assert!(AccessToken::new("").is_none());
+        assert!(AccessToken::new("   \n").is_none());
+        assert!(AccessToken::new(" ghp_abc \n").is_some());
+    }
+
+    #[test]
+    fn test_explicit_token_takes_precedence() {
+        let token = AccessToken::resolve(ProviderKind::GitHub, Some("ghp_explicit".to_string())).unwrap();
+        assert_eq!(token.redact("ghp_explicit"), "***");
+    }
+
+    #[test]
+    fn test_token_never_printed() {
+        let token = AccessToken::new("ghp_secret123").unwrap();
+        assert!(!format!("{:?}", token).contains("ghp_secret123"));
+        assert_eq!(
+            redacted("bad credentials for ghp_secret123".to_string(), Some(&token)),
+            "bad credentials for ***"
+        );
+    }
+
+    #[test]
+    fn test_config_for_enterprise_server() {
+        let config = ServerConfig::for_server(ProviderKind::GitHub, "https://GHE.example.com/").unwrap();
+        assert_eq!(config.host, "ghe.example.com");
+        assert_eq!(config.api_base, "https://ghe.example.com/api/v3");
+        
+        let config = ServerConfig::for_server(ProviderKind::GitHub, "github.com").unwrap();
+        assert_eq!(config.api_base, "https://api.github.com");
+        
+        assert!(ServerConfig::for_server(ProviderKind::GitHub, "ftp://ghe.example.com").is_err());
+    }
+
+    #[test]
+    fn test_config_for_self_managed_gitlab() {
+        let config = ServerConfig::for_server(ProviderKind::GitLab, "http://git.internal.example").unwrap();
+        assert_eq!(config.host, "git.internal.example");
+        assert_eq!(config.api_base, "http://git.internal.example/api/v4");
+        assert_eq!(ServerConfig::default_for(ProviderKind::GitLab).api_base, "https://gitlab.com/api/v4");
+        assert_eq!(ServerConfig::default_for(ProviderKind::Gitea).api_base, "https://codeberg.org/api/v1");
+    }
+
+    #[test]
+    fn test_provider_routing() {
+        let provider = RepoProvider {
+            github: GitHubClient::new(ServerConfig::default_for(ProviderKind::GitHub)).unwrap(),
+            gitlab: GitLabClient::new(ServerConfig::default_for(ProviderKind::GitLab)).unwrap(),
+            gitea: GiteaClient::new(ServerConfig::for_server(ProviderKind::Gitea, "git.example.org").unwrap()).unwrap(),
+            avatars: AvatarCache::new(None).unwrap(),
+        };
+        assert_eq!(provider.kind_for("https://gitlab.com/group/sub/project"), ProviderKind::GitLab);
+        assert_eq!(provider.kind_for("gitlab.com/group/project"), ProviderKind::GitLab);
+        assert_eq!(provider.kind_for("https://git.example.org/team/app"), ProviderKind::Gitea);
+        assert_eq!(provider.kind_for("https://github.com/rust-lang/rust"), ProviderKind::GitHub);
+        assert_eq!(provider.kind_for("rust-lang/rust"), ProviderKind::GitHub);
+    }
+
+    #[tokio::test]
+    async fn test_local_metadata_applies_project_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"demo\"\ndescription = \"A demo crate\"\n").unwrap();
+        fs::write(dir.path().join(PROJECT_CONFIG_FILE), "tagline = \"Demo, but shinier\"\ntopics = [\"cards\"]\n").unwrap();
+        let provider = RepoProvider {
+            github: GitHubClient::new(ServerConfig::default_for(ProviderKind::GitHub)).unwrap(),
+            gitlab: GitLabClient::new(ServerConfig::default_for(ProviderKind::GitLab)).unwrap(),
+            gitea: GiteaClient::new(ServerConfig::default_for(ProviderKind::Gitea)).unwrap(),
+            avatars: AvatarCache::new(None).unwrap(),
+        };
+        let repo = dir.path().to_string_lossy().to_string();
+        
+        let metadata = provider.fetch_repository_metadata(&repo, None).await.unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("Demo, but shinier"));
+        assert_eq!(metadata.topics, vec!["cards"]);
+        assert_eq!(metadata.project_config.unwrap().tagline.as_deref(), Some("Demo, but shinier"));
+        
+        fs::write(dir.path().join(PROJECT_CONFIG_FILE), "colour = \"red\"\n").unwrap();
+        let error = provider.fetch_repository_metadata(&repo, None).await.unwrap_err();
+        assert_eq!(error.kind(), "parse");
+    }
+
+    #[test]
+    fn test_commit_range_parse() {
+        let range = CommitRange::parse("v1.2.0..v1.3.0").unwrap();
+        assert_eq!(range.base.as_deref(), Some("v1.2.0"));
+        assert_eq!(range.head.as_deref(), Some("v1.3.0"));
+        
+        let range = CommitRange::parse("abc1234...").unwrap();
+        assert_eq!(range.base.as_deref(), Some("abc1234"));
+        assert!(range.head.is_none());
+        
+        assert_eq!(CommitRange::parse("develop").unwrap().head.as_deref(), Some("develop"));
+        assert!(CommitRange::parse("..").is_err());
+    }
+
+    #[test]
+    fn test_commit_range_window() {
+        let range = CommitRange {
+            since: Some("2024-03-01".to_string()),
+            until: Some("2024-03-31".to_string()),
+            ..CommitRange::default()
+        };
+        let (since, until) = range.window().unwrap();
+        assert_eq!(format_range_date(since.unwrap()), "2024-03-01T00:00:00Z");
+        assert_eq!(format_range_date(until.unwrap()), "2024-03-31T23:59:59Z");
+        assert!(range.includes_date("2024-03-31T18:00:00Z"));
+        assert!(range.includes_date("2024-04-01T01:00:00+02:00"));
+        assert!(!range.includes_date("2024-04-01T00:00:00Z"));
+        assert!(!range.includes_date("2024-02-29T23:59:59Z"));
+        
+        let invalid = CommitRange {
+            since: Some("last tuesday".to_string()),
+            ..CommitRange::default()
+        };
+        assert!(invalid.window().is_err());
+    }
+
+    #[test]
+    fn test_commit_range_max_commits() {
+        assert_eq!(CommitRange::latest(250).max_commits(), 250);
+        assert_eq!(CommitRange::latest(0).max_commits(), 1);
+        assert_eq!(CommitRange::default().max_commits(), MAX_RANGE_COMMITS);
+    }
+
+    #[test]
+    fn test_previous_tag() {
+        let tags: Vec<String> = ["v1.3.0", "v1.2.0", "v1.1.0"].iter().map(|t| t.to_string()).collect();
+        assert_eq!(previous_tag(&tags, Some("v1.3.0")).as_deref(), Some("v1.2.0"));
+        assert_eq!(previous_tag(&tags, Some("v1.1.0")), None);
+        assert_eq!(previous_tag(&tags, Some("v1.4.0")).as_deref(), Some("v1.3.0"));
+        assert_eq!(previous_tag(&tags, None).as_deref(), Some("v1.3.0"));
+        assert_eq!(previous_tag(&[], None), None);
+    }
+
+    #[test]
+    fn test_version_key_sorts_numerically() {
+        let mut tags = vec!["v1.9.2", "v1.10.0", "v1.2.0"];
+        tags.sort_by_key(|t| std::cmp::Reverse(version_key(t)));
+        assert_eq!(tags, vec!["v1.10.0", "v1.9.2", "v1.2.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_cached_responses_revalidate_and_survive_rate_limits() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        
+        // 200 with an ETag, then 304 to the revalidation, then rate limited for an hour
+        let responses = [
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Type: application/json\r\nContent-Length: 12\r\nConnection: close\r\n\r\n{\"name\":\"b\"}".to_string(),
+            "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n".to_string(),
+            format!(
+                "HTTP/1.1 403 Forbidden\r\nX-RateLimit-Remaining: 0\r\nX-RateLimit-Reset: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                Utc::now().timestamp() + 3600
+            ),
+        ];
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let api_base = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = [0u8; 2048];
+                let read = socket.read(&mut request).await.unwrap();
+                requests.push(String::from_utf8_lossy(&request[..read]).to_lowercase());
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+        
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = ServerConfig {
+            api_base,
+            cache: Some(ResponseCache::new(dir.path(), Duration::from_secs(60))),
+            ..ServerConfig::default_for(ProviderKind::GitHub)
+        };
+        let api = ApiClient::new(ProviderKind::GitHub, &config, "application/json").unwrap();
+        let body = |response: Response| async move { response.text().await.unwrap() };
+        
+        let first = api.send_checked(api.get("repos/a/b", None), None).await.unwrap();
+        assert_eq!(body(first).await, "{\"name\":\"b\"}");
+        // Fresh: answered from disk without a request
+        let fresh = api.send_checked(api.get("repos/a/b", None), None).await.unwrap();
+        assert_eq!(fresh.headers()["content-type"], "application/json");
+        assert_eq!(body(fresh).await, "{\"name\":\"b\"}");
+        
+        let refreshing = api.refreshing();
+        let revalidated = refreshing.send_checked(refreshing.get("repos/a/b", None), None).await.unwrap();
+        assert_eq!(body(revalidated).await, "{\"name\":\"b\"}");
+        let stale = refreshing.send_checked(refreshing.get("repos/a/b", None), None).await.unwrap();
+        assert_eq!(body(stale).await, "{\"name\":\"b\"}");
+        
+        let requests = server.await.unwrap();
+        assert!(!requests[0].contains("if-none-match"));
+        assert!(requests[1].contains("if-none-match: \"v1\""));
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_without_a_cached_copy_is_refetched() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        
+        let responses = [
+            "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 12\r\nConnection: close\r\n\r\n{\"name\":\"b\"}",
+        ];
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let api_base = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = [0u8; 2048];
+                let read = socket.read(&mut request).await.unwrap();
+                requests.push(String::from_utf8_lossy(&request[..read]).to_lowercase());
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+        
+        // An empty cache, so nothing can stand in for the 304's body
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = ServerConfig {
+            api_base,
+            cache: Some(ResponseCache::new(dir.path(), Duration::from_secs(60))),
+            ..ServerConfig::default_for(ProviderKind::GitHub)
+        };
+        let api = ApiClient::new(ProviderKind::GitHub, &config, "application/json").unwrap();
+        let request = api.get("repos/a/b", None).header(IF_NONE_MATCH, "\"v1\"");
+        let response = api.send_checked(request, None).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "{\"name\":\"b\"}");
+        
+        let requests = server.await.unwrap();
+        assert!(requests[0].contains("if-none-match"));
+        assert!(!requests[1].contains("if-none-match"));
+    }
+}
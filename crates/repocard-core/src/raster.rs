@@ -0,0 +1,271 @@
+// RepoCard Studio - Raster Output Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::templates::render_pixmap;
+use serde::{Deserialize, Serialize};
+
+/// Used when no quality is given, for the formats that always need one
+const DEFAULT_JPEG_QUALITY: u8 = 90;
+const DEFAULT_AVIF_QUALITY: u8 = 80;
+/// AVIF encoder speed from 1 (smallest files) to 10 (fastest)
+const AVIF_SPEED: u8 = 6;
+
+/// Image formats cards can be rasterized to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Jpeg,
+    Webp,
+    Avif,
+}
+
+impl ImageFormat {
+    /// `png`, `jpeg` (or `jpg`), `webp` or `avif`, ignoring case
+    pub fn parse(name: &str) -> Result<Self, AppError> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "jpeg" | "jpg" => Ok(Self::Jpeg),
+            "webp" => Ok(Self::Webp),
+            "avif" => Ok(Self::Avif),
+            other => Err(AppError::parse(format!("Unknown image format: {}", other))),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Webp => "webp",
+            Self::Avif => "avif",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+            Self::Avif => "image/avif",
+        }
+    }
+}
+
+/// How a card is encoded after rasterizing
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RasterOptions {
+    pub format: ImageFormat,
+    /// 1–100 for JPEG, WebP and AVIF; WebP is lossless when unset, and PNG ignores it
+    pub quality: Option<u8>,
+    /// Largest file in kilobytes (1000 bytes); lower qualities are tried until the image fits
+    pub max_kb: Option<u32>,
+}
+
+/// Rasterize the SVG `width` pixels wide and encode it as `options` say
+pub fn rasterize_svg_as(svg_content: &str, width: u32, options: &RasterOptions) -> Result<Vec<u8>, AppError> {
+    let pixmap = render_pixmap(svg_content, width)?;
+    let quality = options.quality.map(|q| q.clamp(1, 100));
+    let image = encode(&pixmap, options.format, quality)?;
+
+    let Some(max_kb) = options.max_kb else {
+        return Ok(image);
+    };
+    let budget = max_kb as usize * 1000;
+    if image.len() <= budget {
+        return Ok(image);
+    }
+    if options.format == ImageFormat::Png {
+        return Err(AppError::render(format!(
+            "The PNG card is {} KB, over the {} KB limit; use JPEG, WebP or AVIF to fit it",
+            image.len().div_ceil(1000),
+            max_kb
+        )));
+    }
+
+    // The highest quality below the one that was too big; lossless WebP may go up to 100
+    let tried = quality.or(match options.format {
+        ImageFormat::Jpeg => Some(DEFAULT_JPEG_QUALITY),
+        ImageFormat::Avif => Some(DEFAULT_AVIF_QUALITY),
+        ImageFormat::Png | ImageFormat::Webp => None,
+    });
+    let (mut low, mut high) = (1, tried.map_or(100, |q| q - 1));
+    let mut best = None;
+    while low <= high {
+        let mid = low + (high - low) / 2;
+        let candidate = encode(&pixmap, options.format, Some(mid))?;
+        if candidate.len() <= budget {
+            best = Some(candidate);
+            low = mid + 1;
+        } else {
+            high = mid - 1;
+        }
+    }
+    best.ok_or_else(|| {
+        AppError::render(format!(
+            "The card does not fit in {} KB as {} even at the lowest quality",
+            max_kb,
+            options.format.extension()
+        ))
+    })
+}
+
+/// Encode with `quality`, or each format's default when unset
+fn encode(pixmap: &tiny_skia::Pixmap, format: ImageFormat, quality: Option<u8>) -> Result<Vec<u8>, AppError> {
+    let (width, height) = (pixmap.width(), pixmap.height());
+    match format {
+        ImageFormat::Png => pixmap
+            .encode_png()
+            .map_err(|e| AppError::render(format!("Failed to encode PNG: {}", e))),
+        ImageFormat::Jpeg => {
+            let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+                (Ok(width), Ok(height)) => (width, height),
+                _ => return Err(AppError::render("Cards wider or taller than 65535 pixels cannot be JPEG")),
+            };
+            // JPEG has no alpha, so transparent corners become white
+            let rgb: Vec<u8> = pixmap
+                .pixels()
+                .iter()
+                .flat_map(|p| {
+                    let white = 255 - p.alpha();
+                    [p.red() + white, p.green() + white, p.blue() + white]
+                })
+                .collect();
+            let mut jpeg = Vec::new();
+            jpeg_encoder::Encoder::new(&mut jpeg, quality.unwrap_or(DEFAULT_JPEG_QUALITY))
+                .encode(&rgb, width, height, jpeg_encoder::ColorType::Rgb)
+                .map_err(|e| AppError::render(format!("Failed to encode JPEG: {}", e)))?;
+            Ok(jpeg)
+        }
+        ImageFormat::Webp => {
+            let rgba: Vec<u8> = pixmap
+                .pixels()
+                .iter()
+                .flat_map(|p| {
+                    let c = p.demultiply();
+                    [c.red(), c.green(), c.blue(), c.alpha()]
+                })
+                .collect();
+            let encoder = webp::Encoder::from_rgba(&rgba, width, height);
+            let webp = match quality {
+                Some(quality) => encoder.encode_simple(false, quality as f32),
+                None => encoder.encode_simple(true, 75.0),
+            };
+            webp.map(|data| data.to_vec())
+                .map_err(|e| AppError::render(format!("Failed to encode WebP: {:?}", e)))
+        }
+        ImageFormat::Avif => {
+            let rgba: Vec<ravif::RGBA8> = pixmap
+                .pixels()
+                .iter()
+                .map(|p| {
+                    let c = p.demultiply();
+                    ravif::RGBA8::new(c.red(), c.green(), c.blue(), c.alpha())
+                })
+                .collect();
+            ravif::Encoder::new()
+                .with_quality(quality.unwrap_or(DEFAULT_AVIF_QUALITY) as f32)
+                .with_speed(AVIF_SPEED)
+                .encode_rgba(ravif::Img::new(&rgba[..], width as usize, height as usize))
+                .map(|encoded| encoded.avif_file)
+                .map_err(|e| AppError::render(format!("Failed to encode AVIF: {}", e)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::generate_svg;
+    use crate::test_support::sample_metadata;
+
+    fn card() -> String {
+        generate_svg(&sample_metadata(), "gradient", true, false, false, None, None).unwrap()
+    }
+
+    #[test]
+    fn test_parses_format_names() {
+        assert_eq!(ImageFormat::parse("JPG").unwrap(), ImageFormat::Jpeg);
+        assert_eq!(ImageFormat::parse("webp").unwrap(), ImageFormat::Webp);
+        assert_eq!(ImageFormat::parse("gif").unwrap_err().kind(), "parse");
+        assert_eq!(ImageFormat::Avif.mime_type(), "image/avif");
+        assert_eq!(serde_json::to_string(&ImageFormat::Jpeg).unwrap(), "\"jpeg\"");
+    }
+
+    #[test]
+    fn test_encodes_every_format() {
+        let svg = card();
+        let encoded = |format, quality| {
+            let options = RasterOptions {
+                format,
+                quality,
+                max_kb: None,
+            };
+            rasterize_svg_as(&svg, 300, &options).unwrap()
+        };
+        assert!(encoded(ImageFormat::Png, None).starts_with(b"\x89PNG"));
+        assert!(encoded(ImageFormat::Jpeg, Some(80)).starts_with(&[0xff, 0xd8, 0xff]));
+        let webp = encoded(ImageFormat::Webp, None);
+        assert!(webp.starts_with(b"RIFF") && &webp[8..12] == b"WEBP");
+        assert!(encoded(ImageFormat::Webp, Some(50)).len() < webp.len());
+        assert_eq!(&encoded(ImageFormat::Avif, None)[4..12], b"ftypavif");
+    }
+
+    /// Half the size of the card as a best-quality JPEG, so it only fits at a lower quality
+    fn half_of_full_quality_jpeg(svg: &str) -> u32 {
+        let options = RasterOptions {
+            format: ImageFormat::Jpeg,
+            quality: Some(100),
+            max_kb: None,
+        };
+        (rasterize_svg_as(svg, 600, &options).unwrap().len() / 1000 / 2) as u32
+    }
+
+    #[test]
+    fn test_jpeg_quality_search_fits_the_budget() {
+        let svg = card();
+        let max_kb = half_of_full_quality_jpeg(&svg);
+        let options = RasterOptions {
+            format: ImageFormat::Jpeg,
+            quality: Some(100),
+            max_kb: Some(max_kb),
+        };
+        let fitted = rasterize_svg_as(&svg, 600, &options).unwrap();
+        assert!(fitted.len() <= max_kb as usize * 1000, "{} bytes over {} KB", fitted.len(), max_kb);
+    }
+
+    #[test]
+    fn test_lossless_webp_fits_the_budget() {
+        let svg = card();
+        let max_kb = half_of_full_quality_jpeg(&svg);
+        let options = RasterOptions {
+            format: ImageFormat::Webp,
+            quality: None,
+            max_kb: Some(max_kb),
+        };
+        let fitted = rasterize_svg_as(&svg, 600, &options).unwrap();
+        assert!(fitted.len() <= max_kb as usize * 1000, "{} bytes over {} KB", fitted.len(), max_kb);
+    }
+
+    #[test]
+    fn test_png_over_budget_is_an_error() {
+        let options = RasterOptions {
+            format: ImageFormat::Png,
+            quality: None,
+            max_kb: Some(1),
+        };
+        assert_eq!(rasterize_svg_as(&card(), 600, &options).unwrap_err().kind(), "render");
+    }
+
+    #[test]
+    fn test_zero_budget_is_an_error() {
+        let options = RasterOptions {
+            format: ImageFormat::Jpeg,
+            quality: None,
+            max_kb: Some(0),
+        };
+        assert_eq!(rasterize_svg_as(&card(), 600, &options).unwrap_err().kind(), "render");
+    }
+}
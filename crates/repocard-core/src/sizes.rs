@@ -0,0 +1,182 @@
+// RepoCard Studio - Card Sizes Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::layout::round;
+use serde::{Deserialize, Serialize};
+
+/// The canvas the built-in templates are designed on
+pub(crate) const DESIGN_WIDTH: f32 = 1200.0;
+pub(crate) const DESIGN_HEIGHT: f32 = 630.0;
+/// Room the language row takes when it moves under the contributors
+pub(crate) const STACKED_ROW_GAP: f32 = 70.0;
+/// Most lines a description grows by on tall canvases
+const MAX_EXTRA_LINES: usize = 4;
+
+/// Card sizes social platforms ask for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SizePreset {
+    /// Open Graph, used by most link previews
+    #[default]
+    #[serde(rename = "og")]
+    OpenGraph,
+    #[serde(rename = "twitter")]
+    Twitter,
+    #[serde(rename = "linkedin")]
+    LinkedIn,
+    /// Instagram feed
+    #[serde(rename = "square")]
+    Square,
+    /// Instagram and other vertical stories
+    #[serde(rename = "story")]
+    Story,
+    /// A repository's social preview on GitHub
+    #[serde(rename = "github")]
+    GitHub,
+}
+
+impl SizePreset {
+    pub const ALL: [SizePreset; 6] = [
+        Self::OpenGraph,
+        Self::Twitter,
+        Self::LinkedIn,
+        Self::Square,
+        Self::Story,
+        Self::GitHub,
+    ];
+
+    /// Short name used in file names, flags and configs
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::OpenGraph => "og",
+            Self::Twitter => "twitter",
+            Self::LinkedIn => "linkedin",
+            Self::Square => "square",
+            Self::Story => "story",
+            Self::GitHub => "github",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OpenGraph => "Open Graph",
+            Self::Twitter => "X / Twitter",
+            Self::LinkedIn => "LinkedIn",
+            Self::Square => "Instagram square",
+            Self::Story => "Story",
+            Self::GitHub => "GitHub social preview",
+        }
+    }
+
+    /// Width and height in pixels
+    pub fn dimensions(self) -> (u32, u32) {
+        match self {
+            Self::OpenGraph => (1200, 630),
+            Self::Twitter => (1600, 900),
+            Self::LinkedIn => (1200, 627),
+            Self::Square => (1080, 1080),
+            Self::Story => (1080, 1920),
+            Self::GitHub => (1280, 640),
+        }
+    }
+
+    /// A preset by id, ignoring case; `x` and `open-graph` work too
+    pub fn parse(id: &str) -> Result<Self, AppError> {
+        let id = id.trim().to_ascii_lowercase();
+        match id.as_str() {
+            "x" => Ok(Self::Twitter),
+            "open-graph" | "opengraph" => Ok(Self::OpenGraph),
+            _ => Self::ALL
+                .into_iter()
+                .find(|preset| preset.id() == id)
+                .ok_or_else(|| AppError::parse(format!("Unknown card size: {}", id))),
+        }
+    }
+}
+
+/// A preset's canvas in design units: the 1200×630 design scaled to fit, then grown to fill the rest
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Canvas {
+    pub width: f32,
+    pub height: f32,
+    pub pixel_width: u32,
+    pub pixel_height: u32,
+}
+
+impl Canvas {
+    pub fn new(preset: SizePreset) -> Self {
+        let (pixel_width, pixel_height) = preset.dimensions();
+        let scale = (pixel_width as f32 / DESIGN_WIDTH).min(pixel_height as f32 / DESIGN_HEIGHT);
+        Self {
+            width: round(pixel_width as f32 / scale),
+            height: round(pixel_height as f32 / scale),
+            pixel_width,
+            pixel_height,
+        }
+    }
+
+    /// Room beyond the 1200×630 design, in design units
+    pub fn extra_width(&self) -> f32 {
+        self.width - DESIGN_WIDTH
+    }
+
+    pub fn extra_height(&self) -> f32 {
+        self.height - DESIGN_HEIGHT
+    }
+
+    /// Square and portrait cards put the language row under the contributors instead of beside them
+    pub fn stacks_rows(&self) -> bool {
+        self.height >= self.width
+    }
+
+    /// Lines a description may take: `lines` on the design canvas, more when there is room below
+    pub fn text_lines(&self, lines: usize, line_height: f32) -> usize {
+        let extra = (self.extra_height() / 2.0 / line_height).floor() as usize;
+        lines + extra.min(MAX_EXTRA_LINES)
+    }
+
+    /// How far content moves down to sit in the middle, once it has grown by `grown`
+    pub fn content_offset(&self, grown: f32) -> f32 {
+        let reserved = if self.stacks_rows() { STACKED_ROW_GAP } else { 0.0 };
+        round(((self.extra_height() - grown - reserved) / 2.0).max(0.0))
+    }
+
+    /// The root element, sized in pixels with a viewBox in design units
+    pub fn svg_open(&self) -> String {
+        format!(
+            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg">"#,
+            self.pixel_width, self.pixel_height, self.width, self.height
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_preset_ids() {
+        for preset in SizePreset::ALL {
+            assert_eq!(SizePreset::parse(preset.id()).unwrap(), preset);
+            assert_eq!(serde_json::to_string(&preset).unwrap(), format!("\"{}\"", preset.id()));
+        }
+        assert_eq!(SizePreset::parse("X").unwrap(), SizePreset::Twitter);
+        assert_eq!(SizePreset::parse("banner").unwrap_err().kind(), "parse");
+    }
+
+    #[test]
+    fn test_canvas_keeps_the_design_and_fills_the_rest() {
+        let og = Canvas::new(SizePreset::OpenGraph);
+        assert_eq!((og.width, og.height, og.extra_width(), og.extra_height()), (1200.0, 630.0, 0.0, 0.0));
+        assert!(og.svg_open().contains(r#"width="1200" height="630" viewBox="0 0 1200 630""#));
+
+        for preset in SizePreset::ALL {
+            let canvas = Canvas::new(preset);
+            let (width, height) = preset.dimensions();
+            assert!(canvas.extra_width() >= 0.0 && canvas.extra_height() >= 0.0, "{:?}", preset);
+            assert!((canvas.width / canvas.height - width as f32 / height as f32).abs() < 0.01, "{:?}", preset);
+        }
+        assert!(Canvas::new(SizePreset::Story).stacks_rows());
+        assert!(!Canvas::new(SizePreset::Twitter).stacks_rows());
+    }
+}
@@ -0,0 +1,296 @@
+// RepoCard Studio - Template Registry Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::avatar::image_data_uri;
+use crate::templates::{generate_svg, rasterize_svg};
+use crate::user_templates::{template_context, UserTemplate, DEFAULT_PRIMARY_COLOR, DEFAULT_SECONDARY_COLOR, TEMPLATE_EXTENSION};
+use crate::RepoMetadata;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Width of the thumbnails `render_template_previews` draws when asked for none
+pub const PREVIEW_WIDTH: u32 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateSource {
+    Builtin,
+    User,
+}
+
+/// A colour a template lets the user pick
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColorSlot {
+    /// `primary` or `secondary`, the export option it fills
+    pub slot: String,
+    /// What the template draws with when nothing is picked
+    pub default: String,
+}
+
+/// Everything the UI needs to offer a template without knowing it in advance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateInfo {
+    /// What `template_id` takes: the built-in name, or the file name without `.svg`
+    pub id: String,
+    pub name: String,
+    pub source: TemplateSource,
+    /// The template file, for user templates
+    pub path: Option<String>,
+    /// Canvas size in SVG units
+    pub width: u32,
+    pub height: u32,
+    /// Empty when the colours are fixed
+    pub colors: Vec<ColorSlot>,
+    /// Template context values the card shows, e.g. `stars` or `topics`
+    pub fields: Vec<String>,
+    /// Why a user template cannot be used; broken files are still listed so the problem is visible
+    pub error: Option<String>,
+}
+
+struct BuiltinTemplate {
+    id: &'static str,
+    name: &'static str,
+    colors: &'static [(&'static str, &'static str)],
+    fields: &'static [&'static str],
+}
+
+/// Rows every built-in can add above its footer
+const BUILTIN_ROWS: [&str; 2] = ["contributors", "languages"];
+
+const BUILTINS: [BuiltinTemplate; 3] = [
+    BuiltinTemplate {
+        id: "modern",
+        name: "Modern",
+        colors: &[("primary", "#0d1117"), ("secondary", "#161b22")],
+        fields: &[
+            "owner", "owner_initial", "owner_avatar", "repo", "description", "stars", "forks", "language",
+            "language_color", "latest_release", "topics", "url", "attribution",
+        ],
+    },
+    BuiltinTemplate {
+        id: "minimal",
+        name: "Minimal",
+        colors: &[("primary", "#ffffff")],
+        fields: &[
+            "owner", "repo", "description", "stars", "forks", "open_issues", "language", "language_color",
+            "latest_release", "attribution",
+        ],
+    },
+    BuiltinTemplate {
+        id: "gradient",
+        name: "Gradient",
+        colors: &[("primary", "#667eea"), ("secondary", "#764ba2")],
+        fields: &[
+            "full_name", "description", "stars", "forks", "language", "language_color", "latest_release", "license",
+            "updated_at", "attribution",
+        ],
+    },
+];
+
+/// Whether `id` names a template compiled into the app
+pub fn is_builtin_template(id: &str) -> bool {
+    BUILTINS.iter().any(|builtin| builtin.id == id)
+}
+
+impl TemplateInfo {
+    fn builtin(builtin: &BuiltinTemplate) -> Self {
+        Self {
+            id: builtin.id.to_string(),
+            name: builtin.name.to_string(),
+            source: TemplateSource::Builtin,
+            path: None,
+            width: 1200,
+            height: 630,
+            colors: builtin
+                .colors
+                .iter()
+                .map(|(slot, default)| ColorSlot { slot: slot.to_string(), default: default.to_string() })
+                .collect(),
+            fields: builtin.fields.iter().chain(&BUILTIN_ROWS).map(|f| f.to_string()).collect(),
+            error: None,
+        }
+    }
+
+    fn user(path: &Path) -> Option<Self> {
+        let id = path.file_stem()?.to_str()?.to_string();
+        let mut info = Self {
+            name: display_name(&id),
+            id,
+            source: TemplateSource::User,
+            path: Some(path.to_string_lossy().into_owned()),
+            width: 0,
+            height: 0,
+            colors: Vec::new(),
+            fields: Vec::new(),
+            error: None,
+        };
+        if is_builtin_template(&info.id) {
+            info.error = Some(format!("\"{}\" is a built-in template name; rename the file", info.id));
+            return Some(info);
+        }
+        
+        match UserTemplate::load(path) {
+            Ok(template) => {
+                let slots = [("primary", DEFAULT_PRIMARY_COLOR), ("secondary", DEFAULT_SECONDARY_COLOR)];
+                info.colors = slots
+                    .iter()
+                    .filter(|(slot, _)| template.uses_field(&format!("{}_color", slot)))
+                    .map(|(slot, default)| ColorSlot { slot: slot.to_string(), default: default.to_string() })
+                    .collect();
+                info.name = template.title.clone().unwrap_or(info.name);
+                info.width = template.width;
+                info.height = template.height;
+                info.fields = template.fields;
+            }
+            Err(e) => info.error = Some(e.message().to_string()),
+        }
+        Some(info)
+    }
+}
+
+/// `my-card` → `My Card`
+fn display_name(id: &str) -> String {
+    id.split(['-', '_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Built-in templates followed by every `.svg` file in `dir`, sorted by id
+pub fn list_templates(dir: Option<&Path>) -> Vec<TemplateInfo> {
+    let mut user: Vec<TemplateInfo> = template_files(dir).iter().filter_map(|path| TemplateInfo::user(path)).collect();
+    user.sort_by(|a, b| a.id.cmp(&b.id));
+    BUILTINS.iter().map(TemplateInfo::builtin).chain(user).collect()
+}
+
+fn template_files(dir: Option<&Path>) -> Vec<PathBuf> {
+    dir.and_then(|dir| fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == TEMPLATE_EXTENSION))
+        .collect()
+}
+
+/// A low-resolution rendering of one template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplatePreview {
+    pub id: String,
+    /// PNG `data:` URI, ready for an `<img>`
+    pub image: Option<String>,
+    /// Set instead of `image` when the template could not be drawn
+    pub error: Option<String>,
+}
+
+/// Thumbnails of every listed template for `metadata`, each in its default colours
+pub fn render_template_previews(
+    metadata: &RepoMetadata,
+    dir: Option<&Path>,
+    include_attribution: bool,
+    show_contributors: bool,
+    show_languages: bool,
+    width: u32,
+) -> Vec<TemplatePreview> {
+    list_templates(dir)
+        .into_iter()
+        .map(|info| {
+            let svg = match (&info.error, &info.path) {
+                (Some(error), _) => Err(error.clone()),
+                (None, Some(path)) => UserTemplate::load(Path::new(path))
+                    .map(|template| {
+                        let context =
+                            template_context(metadata, include_attribution, show_contributors, show_languages, None, None);
+                        template.render(&context)
+                    })
+                    .map_err(|e| e.message().to_string()),
+                (None, None) => {
+                    generate_svg(metadata, &info.id, include_attribution, show_contributors, show_languages, None, None)
+                        .map_err(|e| e.message().to_string())
+                }
+            };
+            let image = svg.and_then(|svg| {
+                let png = rasterize_svg(&svg, width).map_err(|e| e.message().to_string())?;
+                image_data_uri(&png).ok_or_else(|| "Rendered preview is not an image".to_string())
+            });
+            match image {
+                Ok(image) => TemplatePreview { id: info.id, image: Some(image), error: None },
+                Err(error) => TemplatePreview { id: info.id, image: None, error: Some(error) },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metadata;
+    use tempfile::TempDir;
+
+    const CARD: &str = r##"<svg width="800" height="418" xmlns="http://www.w3.org/2000/svg">
+  <title>Night Sky</title>
+  <rect width="800" height="418" fill="{{secondary_color}}"/>
+  <text x="40" y="80" fill="#fff">{{full_name}} ★ {{stars}}</text>
+</svg>"##;
+
+    #[test]
+    fn test_lists_user_templates_after_builtins() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("night-sky.svg"), CARD).unwrap();
+        fs::write(dir.path().join("plain_card.svg"), CARD.replace("<title>Night Sky</title>", "")).unwrap();
+        fs::write(dir.path().join("broken.svg"), "<svg>{{nope}}</svg>").unwrap();
+        fs::write(dir.path().join("modern.svg"), CARD).unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a template").unwrap();
+        
+        let templates = list_templates(Some(dir.path()));
+        let ids: Vec<&str> = templates.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["modern", "minimal", "gradient", "broken", "modern", "night-sky", "plain_card"]);
+        assert!(templates[..3].iter().all(|t| t.source == TemplateSource::Builtin && t.path.is_none()));
+        assert!(templates[3].error.as_deref().unwrap().contains("unknown placeholder {{nope}}"));
+        assert!(templates[4].error.as_deref().unwrap().contains("built-in template name"));
+        
+        let night = &templates[5];
+        assert_eq!(night.error, None);
+        assert_eq!(night.name, "Night Sky");
+        assert_eq!((night.width, night.height), (800, 418));
+        assert_eq!(night.colors, [ColorSlot { slot: "secondary".to_string(), default: DEFAULT_SECONDARY_COLOR.to_string() }]);
+        assert_eq!(night.fields, ["full_name", "secondary_color", "stars"]);
+        assert_eq!(templates[6].name, "Plain Card");
+        assert_eq!(list_templates(None).len(), 3);
+    }
+
+    #[test]
+    fn test_builtin_defaults_match_rendered_cards() {
+        let metadata = sample_metadata();
+        for info in list_templates(None) {
+            let svg = generate_svg(&metadata, &info.id, true, false, false, None, None).unwrap();
+            assert!(svg.contains(&format!("width=\"{}\" height=\"{}\"", info.width, info.height)), "{}", info.id);
+            for color in &info.colors {
+                assert!(svg.contains(&color.default), "{} does not draw with {}", info.id, color.default);
+            }
+        }
+        assert!(is_builtin_template("gradient"));
+        assert!(!is_builtin_template("night-sky"));
+    }
+
+    #[test]
+    fn test_renders_previews_of_every_template() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("night-sky.svg"), CARD).unwrap();
+        fs::write(dir.path().join("broken.svg"), "<svg>{{#if stars}}</svg>").unwrap();
+        
+        let previews = render_template_previews(&sample_metadata(), Some(dir.path()), true, false, true, 120);
+        let ids: Vec<&str> = previews.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["modern", "minimal", "gradient", "broken", "night-sky"]);
+        for preview in previews.iter().filter(|p| p.id != "broken") {
+            assert!(preview.image.as_deref().unwrap().starts_with("data:image/png;base64,"), "{}", preview.id);
+            assert_eq!(preview.error, None);
+        }
+        assert_eq!(previews[3].image, None);
+        assert!(previews[3].error.as_deref().unwrap().contains("never closed"));
+    }
+}
@@ -0,0 +1,1664 @@
+// RepoCard Studio - SVG Templates Module
+// LAZYFROG (KZ) — kindware.dev
+
+use crate::error::AppError;
+use crate::languages::{language_color, DEFAULT_LANGUAGE_COLOR};
+use crate::layout::{ellipsize, render_options, round, text_width, FontWeight, TextBlock, TextBox, CARD_FONT_FAMILY};
+use crate::sizes::{Canvas, STACKED_ROW_GAP};
+use crate::user_templates::{template_context, user_templates_dir, UserTemplate};
+use crate::{CommitInfo, CommitRange, ContributorInfo, ExportOptions, LanguageStat, ReleaseNoteCategory, RepoMetadata, SizePreset};
+use chrono::Utc;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub(crate) const ATTRIBUTION_TEXT: &str = "Generated with RepoCard Studio — LAZYFROG (KZ) — kindware.dev";
+/// Line height of wrapped descriptions, relative to their font size
+const DESCRIPTION_LINE_HEIGHT: f32 = 1.3;
+
+/// Format large numbers with K/M suffix
+pub(crate) fn format_count(count: u32) -> String {
+    if count >= 1_000_000 {
+        format!("{:.1}M", count as f64 / 1_000_000.0)
+    } else if count >= 1_000 {
+        format!("{:.1}K", count as f64 / 1_000.0)
+    } else {
+        count.to_string()
+    }
+}
+
+/// Escape XML special characters, dropping control characters XML cannot hold at all
+pub(crate) fn escape_xml(s: &str) -> String {
+    s.chars()
+        .filter(|&c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .filter(|&c| !matches!(c, '\u{fffe}' | '\u{ffff}'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Refuse anything but a `#rgb`, `#rrggbb` or `#rrggbbaa` hex colour; colours are written into SVG attributes
+pub fn validate_color(color: &str) -> Result<(), AppError> {
+    let valid = color
+        .strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit()));
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::parse(format!("Colours must be #rgb, #rrggbb or #rrggbbaa: {}", color)))
+    }
+}
+
+/// Truncate text to `max_width` terminal columns with an ellipsis, never splitting a grapheme
+pub(crate) fn truncate(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
+    }
+    let budget = max_width.saturating_sub(3);
+    let mut width = 0;
+    let kept: String = s
+        .graphemes(true)
+        .take_while(|grapheme| {
+            width += grapheme.width();
+            width <= budget
+        })
+        .collect();
+    format!("{}...", kept)
+}
+
+/// The first grapheme of a name, upper-cased, for avatar placeholders
+pub(crate) fn initial(name: &str) -> String {
+    name.trim().graphemes(true).next().map(str::to_uppercase).unwrap_or_else(|| "?".to_string())
+}
+
+/// The `YYYY-MM-DD` part of a timestamp, or all of it when it is not one
+pub(crate) fn date_part(timestamp: &str) -> &str {
+    timestamp.get(..10).unwrap_or(timestamp)
+}
+
+/// Generate SVG card based on template, at the Open Graph size
+pub fn generate_svg(
+    metadata: &RepoMetadata,
+    template_id: &str,
+    include_attribution: bool,
+    show_contributors: bool,
+    show_languages: bool,
+    primary_color: Option<String>,
+    secondary_color: Option<String>,
+) -> Result<String, AppError> {
+    let options = ExportOptions {
+        template_id: template_id.to_string(),
+        include_attribution,
+        show_contributors,
+        show_languages,
+        primary_color,
+        secondary_color,
+        ..ExportOptions::default()
+    };
+    generate_card_svg(metadata, &options)
+}
+
+/// The card `options` describe, laid out for `options.size`
+pub fn generate_card_svg(metadata: &RepoMetadata, options: &ExportOptions) -> Result<String, AppError> {
+    for color in options.primary_color.iter().chain(&options.secondary_color) {
+        validate_color(color)?;
+    }
+    let canvas = Canvas::new(options.size);
+    let template_id = options.template_id.as_str();
+    let (primary_color, secondary_color) = (options.primary_color.clone(), options.secondary_color.clone());
+    let (svg, rows_y) = match template_id {
+        "modern" => generate_modern_template(metadata, &canvas, options.include_attribution, primary_color, secondary_color),
+        "minimal" => generate_minimal_template(metadata, &canvas, options.include_attribution, primary_color, secondary_color),
+        "gradient" => generate_gradient_template(metadata, &canvas, options.include_attribution, primary_color, secondary_color),
+        _ => {
+            let dir = user_templates_dir().ok_or_else(|| AppError::template(format!("Unknown template: {}", template_id)))?;
+            let context = template_context(
+                metadata,
+                options.include_attribution,
+                options.show_contributors,
+                options.show_languages,
+                primary_color,
+                secondary_color,
+            );
+            let template = UserTemplate::find(&dir, template_id)?;
+            return Ok(fit_to_canvas(&template.render(&context), template.width, template.height, &canvas));
+        }
+    };
+    
+    let mut rows = String::new();
+    let show_contributors = options.show_contributors && !metadata.contributors.is_empty();
+    
+    // Each template leaves room for these rows just above its footer
+    if show_contributors {
+        let (x, ring) = match template_id {
+            "minimal" => (100.0, "#ffffff"),
+            "gradient" => (140.0, "rgba(255,255,255,0.6)"),
+            _ => (80.0, "#161b22"),
+        };
+        rows.push_str(&format!(
+            "  <!-- Contributors -->\n  <g transform=\"translate({}, {})\">\n    {}\n  </g>\n",
+            x,
+            rows_y,
+            generate_contributors_svg(&metadata.contributors, 8, ring)
+        ));
+    }
+    
+    if options.show_languages && !metadata.languages.is_empty() {
+        let (x, stacked_x, width, text_color) = match template_id {
+            "minimal" => (700.0, 100.0, 400, "#6b7280"),
+            "gradient" => (680.0, 140.0, 380, "rgba(255,255,255,0.85)"),
+            _ => (720.0, 80.0, 400, "#8b949e"),
+        };
+        // Beside the contributors, or under them on tall cards
+        let (x, y) = match (canvas.stacks_rows(), show_contributors) {
+            (true, true) => (stacked_x, rows_y + STACKED_ROW_GAP),
+            (true, false) => (stacked_x, rows_y),
+            (false, _) => (x + canvas.extra_width(), rows_y),
+        };
+        rows.push_str(&format!(
+            "  <!-- Languages -->\n  <g transform=\"translate({}, {})\">\n    {}\n  </g>\n",
+            round(x),
+            y,
+            generate_languages_svg(&metadata.languages, width, text_color)
+        ));
+    }
+    
+    if rows.is_empty() {
+        return Ok(svg);
+    }
+    rows.push_str("</svg>");
+    Ok(svg.replacen("</svg>", &rows, 1))
+}
+
+/// Lines a description may wrap to on `canvas`, two on the design canvas
+fn description_lines(canvas: &Canvas, font_size: f32) -> usize {
+    canvas.text_lines(2, font_size * DESCRIPTION_LINE_HEIGHT)
+}
+
+/// How much taller a description is than the two lines the design leaves room for
+fn description_growth(description: &TextBlock) -> f32 {
+    round(description.lines.len().saturating_sub(2) as f32 * description.font_size * DESCRIPTION_LINE_HEIGHT)
+}
+
+/// A user template drawn on `canvas`, scaled to fit and centred when its own size differs
+fn fit_to_canvas(svg: &str, width: u32, height: u32, canvas: &Canvas) -> String {
+    if (width, height) == (canvas.pixel_width, canvas.pixel_height) {
+        return svg.to_string();
+    }
+    let body = match svg.trim_start().strip_prefix("<?xml") {
+        Some(rest) => rest.split_once("?>").map_or("", |(_, body)| body),
+        None => svg,
+    };
+    let (canvas_width, canvas_height) = (canvas.pixel_width as f32, canvas.pixel_height as f32);
+    let scale = (canvas_width / width as f32).min(canvas_height / height as f32);
+    format!(
+        r#"<svg width="{w}" height="{h}" viewBox="0 0 {w} {h}" xmlns="http://www.w3.org/2000/svg"><g transform="translate({x}, {y}) scale({scale})">{body}</g></svg>"#,
+        w = canvas.pixel_width,
+        h = canvas.pixel_height,
+        x = round((canvas_width - width as f32 * scale) / 2.0),
+        y = round((canvas_height - height as f32 * scale) / 2.0),
+        scale = scale,
+        body = body.trim(),
+    )
+}
+
+/// Modern template - clean, professional look
+fn generate_modern_template(
+    metadata: &RepoMetadata,
+    canvas: &Canvas,
+    include_attribution: bool,
+    primary_color: Option<String>,
+    secondary_color: Option<String>,
+) -> (String, f32) {
+    let primary = primary_color.unwrap_or_else(|| "#0d1117".to_string());
+    let secondary = secondary_color.unwrap_or_else(|| "#161b22".to_string());
+    let lang_color = metadata.language.as_deref().map(language_color).unwrap_or(DEFAULT_LANGUAGE_COLOR);
+    let language = metadata.language.as_deref().unwrap_or("Unknown");
+    let content_width = 1040.0 + canvas.extra_width();
+    let description = TextBox { width: content_width, max_lines: description_lines(canvas, 20.0), font_size: 20.0, min_font_size: 16.0, weight: FontWeight::Normal }
+        .fit(metadata.description.as_deref().unwrap_or("No description provided"));
+    let grown = description_growth(&description);
+    let dy = canvas.content_offset(grown);
+    let release_x = (264.0 + text_width(language, 16.0, FontWeight::Normal) + 32.0).max(400.0);
+    
+    let release_svg = match &metadata.latest_release {
+        Some(tag) => format!(r##"<!-- Latest Release -->
+    <g transform="translate({}, 0)">
+      <path d="M2 1.75C2 .784 2.784 0 3.75 0h4.19c.464 0 .909.184 1.237.513l5.25 5.25a1.75 1.75 0 0 1 0 2.474l-4.19 4.19a1.75 1.75 0 0 1-2.474 0l-5.25-5.25A1.75 1.75 0 0 1 2 5.94ZM5.5 4a1 1 0 1 0 0 2 1 1 0 0 0 0-2Z" fill="#f0f6fc" transform="scale(1.2)"/>
+      <text x="28" y="14" font-size="16" fill="#f0f6fc" font-family="{CARD_FONT_FAMILY}">{}</text>
+    </g>"##, round(release_x), escape_xml(&truncate(tag, 24))),
+        None => String::new(),
+    };
+    
+    // Only embedded avatars: remote images would not render in PNGs or offline
+    let avatar_svg = match metadata.owner.avatar_data_uri.as_deref().filter(|uri| uri.starts_with("data:image/")) {
+        Some(uri) => format!(
+            "\n    <!-- Owner Avatar -->\n    <image href=\"{}\" width=\"80\" height=\"80\" clip-path=\"url(#avatar-clip)\"/>",
+            escape_xml(uri)
+        ),
+        None => String::new(),
+    };
+    
+    let attribution_svg = if include_attribution {
+        format!(r##"<text x="{}" text-anchor="middle" font-size="10" fill="#6e7681" font-family="{CARD_FONT_FAMILY}">{}</text>"##, round(canvas.width / 2.0), ATTRIBUTION_TEXT)
+    } else {
+        String::new()
+    };
+
+    let svg = format!(r##"<!-- KZ: LAZYFROG :: frogprints -->
+{svg_open}
+  <defs>
+    <clipPath id="avatar-clip">
+      <circle cx="40" cy="40" r="40"/>
+    </clipPath>
+    <filter id="shadow" x="-20%" y="-20%" width="140%" height="140%">
+      <feDropShadow dx="0" dy="4" stdDeviation="8" flood-opacity="0.25"/>
+    </filter>
+  </defs>
+  
+  <!-- Background -->
+  <rect width="{width}" height="{height}" fill="{primary}"/>
+  <rect x="40" y="40" width="{panel_width}" height="{panel_height}" rx="16" fill="{secondary}" filter="url(#shadow)"/>
+  
+  <!-- Frogprints Easter Egg -->
+  <g opacity="0.03" transform="translate({corner_x}, {corner_y})">
+    <circle cx="1140" cy="600" r="4"/>
+    <circle cx="1152" cy="608" r="3"/>
+    <circle cx="1160" cy="598" r="3"/>
+  </g>
+  
+  <!-- Header -->
+  <g transform="translate(80, {header_y})">
+    <!-- Owner Avatar Placeholder -->
+    <circle cx="40" cy="40" r="40" fill="#30363d"/>
+    <text x="40" y="48" text-anchor="middle" font-size="24" fill="#8b949e" font-family="{CARD_FONT_FAMILY}">{owner_initial}</text>{avatar}
+    
+    <!-- Repo Name -->
+    <text x="100" y="30" font-size="32" font-weight="bold" fill="#f0f6fc" font-family="{CARD_FONT_FAMILY}">{owner}</text>
+    <text x="100" y="65" font-size="28" fill="#8b949e" font-family="{CARD_FONT_FAMILY}">/ {repo}</text>
+  </g>
+  
+  <!-- Description -->
+  <text x="80" y="{description_y}" font-size="{description_size}" fill="#c9d1d9" font-family="{CARD_FONT_FAMILY}">
+    {description}
+  </text>
+  
+  <!-- Stats -->
+  <g transform="translate(80, {stats_y})">
+    <!-- Stars -->
+    <g transform="translate(0, 0)">
+      <path d="M8 0C3.58 0 0 3.58 0 8s3.58 8 8 8 8-3.58 8-8-3.58-8-8-8zm0 14.5c-3.59 0-6.5-2.91-6.5-6.5S4.41 1.5 8 1.5s6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5z" fill="#f0f6fc" transform="scale(1.2)"/>
+      <path d="M8 3.5l1.5 3 3.5.5-2.5 2.5.5 3.5L8 11l-3 2 .5-3.5L3 7l3.5-.5z" fill="#f0f6fc" transform="scale(1.2)"/>
+      <text x="28" y="14" font-size="16" fill="#f0f6fc" font-family="{CARD_FONT_FAMILY}">{stars}</text>
+    </g>
+    
+    <!-- Forks -->
+    <g transform="translate(120, 0)">
+      <path d="M5 5.372v.878c0 .414.336.75.75.75h4.5a.75.75 0 0 0 .75-.75v-.878a2.25 2.25 0 1 0-1.5 0v.878H6.25v-.878a2.25 2.25 0 1 0-1.5 0ZM8 1.25a1.25 1.25 0 1 1 0 2.5 1.25 1.25 0 0 1 0-2.5ZM5 4a1.25 1.25 0 1 1 0-2.5A1.25 1.25 0 0 1 5 4Zm6 0a1.25 1.25 0 1 1 0-2.5A1.25 1.25 0 0 1 11 4Z" fill="#f0f6fc" transform="scale(1.2)"/>
+      <text x="28" y="14" font-size="16" fill="#f0f6fc" font-family="{CARD_FONT_FAMILY}">{forks}</text>
+    </g>
+    
+    <!-- Language -->
+    <g transform="translate(240, 0)">
+      <circle cx="8" cy="8" r="6" fill="{lang_color}"/>
+      <text x="24" y="14" font-size="16" fill="#f0f6fc" font-family="{CARD_FONT_FAMILY}">{language}</text>
+    </g>
+    {release_svg}
+  </g>
+  
+  <!-- Topics -->
+  <g transform="translate(80, {topics_y})">
+    {topics_svg}
+  </g>
+  
+  <!-- Footer -->
+  <g transform="translate(80, {footer_y})">
+    <text font-size="14" fill="#6e7681" font-family="{CARD_FONT_FAMILY}">{url}</text>
+  </g>
+  
+  <!-- Attribution -->
+  <g transform="translate(0, {attribution_y})">
+    {attribution}
+  </g>
+</svg>"##,
+        svg_open = canvas.svg_open(),
+        width = canvas.width,
+        height = canvas.height,
+        panel_width = round(canvas.width - 80.0),
+        panel_height = round(canvas.height - 80.0),
+        corner_x = round(canvas.extra_width()),
+        corner_y = round(canvas.extra_height()),
+        header_y = 80.0 + dy,
+        description_y = 200.0 + dy,
+        stats_y = 280.0 + dy + grown,
+        topics_y = 360.0 + dy + grown,
+        footer_y = round(canvas.height - 110.0),
+        attribution_y = round(canvas.height - 60.0),
+        primary = primary,
+        secondary = secondary,
+        owner = escape_xml(&ellipsize(&metadata.owner.login, 940.0 + canvas.extra_width(), 32.0, FontWeight::Bold)),
+        owner_initial = escape_xml(&initial(&metadata.owner.login)),
+        avatar = avatar_svg,
+        repo = escape_xml(&ellipsize(&metadata.name, 915.0 + canvas.extra_width(), 28.0, FontWeight::Normal)),
+        description_size = description.font_size,
+        description = description.tspans(80.0, DESCRIPTION_LINE_HEIGHT),
+        stars = format_count(metadata.stargazers_count),
+        forks = format_count(metadata.forks_count),
+        language = escape_xml(language),
+        lang_color = lang_color,
+        release_svg = release_svg,
+        topics_svg = generate_topics_svg(&metadata.topics, 5, content_width),
+        url = escape_xml(&metadata.html_url),
+        attribution = attribution_svg,
+    );
+    (svg, 430.0 + dy + grown)
+}
+
+/// Minimal template - simple, text-focused design
+fn generate_minimal_template(
+    metadata: &RepoMetadata,
+    canvas: &Canvas,
+    include_attribution: bool,
+    primary_color: Option<String>,
+    _secondary_color: Option<String>,
+) -> (String, f32) {
+    let primary = primary_color.unwrap_or_else(|| "#ffffff".to_string());
+    let content_width = 1000.0 + canvas.extra_width();
+    let title = TextBox { width: content_width, max_lines: 1, font_size: 48.0, min_font_size: 28.0, weight: FontWeight::Bold }
+        .fit(&format!("{} / {}", metadata.owner.login, metadata.name));
+    let title_line = title.lines.first().map(String::as_str).unwrap_or_default();
+    // Logins have no spaces, so the first " / " is the separator unless the ellipsis cut it off
+    let (owner, repo) = match title_line.split_once(" / ") {
+        Some((owner, repo)) => (owner, format!(" / {}", repo)),
+        None => (title_line, String::new()),
+    };
+    let description = TextBox { width: content_width, max_lines: description_lines(canvas, 24.0), font_size: 24.0, min_font_size: 18.0, weight: FontWeight::Normal }
+        .fit(metadata.description.as_deref().unwrap_or("No description provided"));
+    let grown = description_growth(&description);
+    let dy = canvas.content_offset(grown);
+    let language = metadata.language.as_deref().unwrap_or("Unknown");
+    let badge_width = (36.0 + text_width(language, 14.0, FontWeight::Normal) + 16.0).max(120.0);
+    
+    let release_svg = match &metadata.latest_release {
+        Some(tag) => format!(r##"<!-- Latest Release Badge -->
+    <g transform="translate({}, {})">
+      <rect width="180" height="32" rx="16" fill="#f3f4f6"/>
+      <text x="20" y="21" font-size="14" fill="#374151" font-family="{CARD_FONT_FAMILY}">Latest <tspan font-weight="bold">{}</tspan></text>
+    </g>"##, round(badge_width + 20.0), 220.0 + grown, escape_xml(&truncate(tag, 16))),
+        None => String::new(),
+    };
+    
+    let attribution_svg = if include_attribution {
+        format!(r##"<text x="{}" y="{}" text-anchor="middle" font-size="11" fill="#9ca3af" font-family="{CARD_FONT_FAMILY}">{}</text>"##, round(canvas.width / 2.0), round(canvas.height - 30.0), ATTRIBUTION_TEXT)
+    } else {
+        String::new()
+    };
+
+    let svg = format!(r##"<!-- KZ: LAZYFROG :: frogprints -->
+{svg_open}
+  <!-- Background -->
+  <rect width="{width}" height="{height}" fill="{primary}"/>
+  
+  <!-- Frogprints Easter Egg -->
+  <g opacity="0.025" fill="#000000" transform="translate({corner_x}, {corner_y})">
+    <circle cx="1140" cy="600" r="4"/>
+    <circle cx="1152" cy="608" r="3"/>
+    <circle cx="1160" cy="598" r="3"/>
+  </g>
+  
+  <!-- Content -->
+  <g transform="translate(100, {content_y})">
+    <!-- Repo Name -->
+    <text font-size="{title_size}" font-weight="bold" fill="#111827" font-family="{CARD_FONT_FAMILY}">
+      <tspan fill="#6b7280">{owner}</tspan><tspan fill="#111827">{repo}</tspan>
+    </text>
+    
+    <!-- Description -->
+    <text y="80" font-size="{description_size}" fill="#4b5563" font-family="{CARD_FONT_FAMILY}">{description}</text>
+    
+    <!-- Stats Row -->
+    <g transform="translate(0, {stats_y})">
+      <text font-size="20" fill="#6b7280" font-family="{CARD_FONT_FAMILY}">
+        <tspan font-weight="bold" fill="#111827">{stars}</tspan> stars
+        <tspan dx="40" font-weight="bold" fill="#111827">{forks}</tspan> forks
+        <tspan dx="40" font-weight="bold" fill="#111827">{issues}</tspan> issues
+      </text>
+    </g>
+    
+    <!-- Language Badge -->
+    <g transform="translate(0, {badge_y})">
+      <rect width="{badge_width}" height="32" rx="16" fill="#f3f4f6"/>
+      <circle cx="20" cy="16" r="6" fill="{lang_color}"/>
+      <text x="36" y="21" font-size="14" fill="#374151" font-family="{CARD_FONT_FAMILY}">{language}</text>
+    </g>
+    {release_svg}
+  </g>
+  
+  <!-- Attribution -->
+  {attribution}
+</svg>"##,
+        svg_open = canvas.svg_open(),
+        width = canvas.width,
+        height = canvas.height,
+        corner_x = round(canvas.extra_width()),
+        corner_y = round(canvas.extra_height()),
+        content_y = 180.0 + dy,
+        stats_y = 160.0 + grown,
+        badge_y = 220.0 + grown,
+        primary = primary,
+        title_size = title.font_size,
+        owner = escape_xml(owner),
+        repo = escape_xml(&repo),
+        description_size = description.font_size,
+        description = description.tspans(0.0, DESCRIPTION_LINE_HEIGHT),
+        stars = format_count(metadata.stargazers_count),
+        forks = format_count(metadata.forks_count),
+        issues = format_count(metadata.open_issues_count),
+        language = escape_xml(language),
+        badge_width = round(badge_width),
+        lang_color = metadata.language.as_deref().map(language_color).unwrap_or(DEFAULT_LANGUAGE_COLOR),
+        release_svg = release_svg,
+        attribution = attribution_svg,
+    );
+    (svg, 470.0 + dy + grown)
+}
+
+/// Gradient template - vibrant, eye-catching design
+fn generate_gradient_template(
+    metadata: &RepoMetadata,
+    canvas: &Canvas,
+    include_attribution: bool,
+    primary_color: Option<String>,
+    secondary_color: Option<String>,
+) -> (String, f32) {
+    let primary = primary_color.unwrap_or_else(|| "#667eea".to_string());
+    let secondary = secondary_color.unwrap_or_else(|| "#764ba2".to_string());
+    let content_width = 920.0 + canvas.extra_width();
+    let title = TextBox { width: content_width, max_lines: 1, font_size: 56.0, min_font_size: 32.0, weight: FontWeight::Bold }
+        .fit(&metadata.full_name);
+    let description = TextBox { width: content_width, max_lines: description_lines(canvas, 22.0), font_size: 22.0, min_font_size: 18.0, weight: FontWeight::Normal }
+        .fit(metadata.description.as_deref().unwrap_or("No description provided"));
+    let grown = description_growth(&description);
+    let dy = canvas.content_offset(grown);
+    // The glass card grows with the description, and with the language row when it stacks
+    let stacked = if canvas.stacks_rows() { STACKED_ROW_GAP } else { 0.0 };
+    let language = metadata.language.as_deref().unwrap_or("Unknown");
+    let pill_width = (44.0 + text_width(language, 16.0, FontWeight::Bold) + 20.0).max(140.0);
+    
+    let release_svg = match &metadata.latest_release {
+        Some(tag) => format!(r##"<!-- Latest Release -->
+      <g transform="translate({}, 0)">
+        <rect width="180" height="40" rx="20" fill="rgba(255,255,255,0.2)"/>
+        <text x="90" y="27" text-anchor="middle" font-size="16" font-weight="bold" fill="#ffffff" font-family="{CARD_FONT_FAMILY}">Latest {}</text>
+      </g>"##, round((240.0 + pill_width + 20.0).max(400.0)), escape_xml(&truncate(tag, 12))),
+        None => String::new(),
+    };
+    
+    let attribution_svg = if include_attribution {
+        format!(r#"<text x="{}" y="{}" text-anchor="middle" font-size="11" fill="rgba(255,255,255,0.7)" font-family="{CARD_FONT_FAMILY}">{}</text>"#, round(canvas.width / 2.0), round(canvas.height - 30.0), ATTRIBUTION_TEXT)
+    } else {
+        String::new()
+    };
+
+    let svg = format!(r##"<!-- KZ: LAZYFROG :: frogprints -->
+{svg_open}
+  <defs>
+    <linearGradient id="bg-gradient" x1="0%" y1="0%" x2="100%" y2="100%">
+      <stop offset="0%" style="stop-color:{primary};stop-opacity:1" />
+      <stop offset="100%" style="stop-color:{secondary};stop-opacity:1" />
+    </linearGradient>
+    <filter id="glow" x="-20%" y="-20%" width="140%" height="140%">
+      <feGaussianBlur stdDeviation="2" result="coloredBlur"/>
+      <feMerge>
+        <feMergeNode in="coloredBlur"/>
+        <feMergeNode in="SourceGraphic"/>
+      </feMerge>
+    </filter>
+  </defs>
+  
+  <!-- Gradient Background -->
+  <rect width="{width}" height="{height}" fill="url(#bg-gradient)"/>
+  
+  <!-- Frogprints Easter Egg -->
+  <g opacity="0.04" fill="#ffffff" transform="translate({corner_x}, {corner_y})">
+    <circle cx="1140" cy="600" r="4"/>
+    <circle cx="1152" cy="608" r="3"/>
+    <circle cx="1160" cy="598" r="3"/>
+  </g>
+  
+  <!-- Decorative Elements -->
+  <circle cx="100" cy="100" r="200" fill="rgba(255,255,255,0.05)"/>
+  <circle cx="{circle_x}" cy="{circle_y}" r="250" fill="rgba(255,255,255,0.05)"/>
+  
+  <!-- Content Card -->
+  <rect x="80" y="{card_y}" width="{card_width}" height="{card_height}" rx="24" fill="rgba(255,255,255,0.1)" stroke="rgba(255,255,255,0.2)" stroke-width="1"/>
+  
+  <!-- Content -->
+  <g transform="translate(140, {content_y})">
+    <!-- Repo Name -->
+    <text font-size="{title_size}" font-weight="bold" fill="#ffffff" font-family="{CARD_FONT_FAMILY}" filter="url(#glow)">{full_name}</text>
+    
+    <!-- Description -->
+    <text y="80" font-size="{description_size}" fill="rgba(255,255,255,0.9)" font-family="{CARD_FONT_FAMILY}">{description}</text>
+    
+    <!-- Stats -->
+    <g transform="translate(0, {stats_y})">
+      <!-- Stars -->
+      <g>
+        <rect width="100" height="40" rx="20" fill="rgba(255,255,255,0.2)"/>
+        <text x="50" y="27" text-anchor="middle" font-size="16" font-weight="bold" fill="#ffffff" font-family="{CARD_FONT_FAMILY}">★ {stars}</text>
+      </g>
+      
+      <!-- Forks -->
+      <g transform="translate(120, 0)">
+        <rect width="100" height="40" rx="20" fill="rgba(255,255,255,0.2)"/>
+        <text x="50" y="27" text-anchor="middle" font-size="16" font-weight="bold" fill="#ffffff" font-family="{CARD_FONT_FAMILY}">⑂ {forks}</text>
+      </g>
+      
+      <!-- Language -->
+      <g transform="translate(240, 0)">
+        <rect width="{pill_width}" height="40" rx="20" fill="rgba(255,255,255,0.2)"/>
+        <circle cx="24" cy="20" r="8" fill="{lang_color}"/>
+        <text x="44" y="27" font-size="16" font-weight="bold" fill="#ffffff" font-family="{CARD_FONT_FAMILY}">{language}</text>
+      </g>
+      {release_svg}
+    </g>
+    
+    <!-- License -->
+    <g transform="translate(0, {license_y})">
+      <text font-size="14" fill="rgba(255,255,255,0.7)" font-family="{CARD_FONT_FAMILY}">
+        {license} • Updated {updated}
+      </text>
+    </g>
+  </g>
+  
+  <!-- Attribution -->
+  {attribution}
+</svg>"##,
+        svg_open = canvas.svg_open(),
+        width = canvas.width,
+        height = canvas.height,
+        corner_x = round(canvas.extra_width()),
+        corner_y = round(canvas.extra_height()),
+        circle_x = round(canvas.width - 100.0),
+        circle_y = round(canvas.height - 100.0),
+        card_y = 120.0 + dy,
+        card_width = round(canvas.width - 160.0),
+        card_height = 400.0 + grown + stacked,
+        content_y = 180.0 + dy,
+        stats_y = 160.0 + grown,
+        license_y = 230.0 + grown,
+        primary = primary,
+        secondary = secondary,
+        title_size = title.font_size,
+        full_name = escape_xml(title.lines.first().map(String::as_str).unwrap_or_default()),
+        description_size = description.font_size,
+        description = description.tspans(0.0, DESCRIPTION_LINE_HEIGHT),
+        stars = format_count(metadata.stargazers_count),
+        forks = format_count(metadata.forks_count),
+        language = escape_xml(language),
+        pill_width = round(pill_width),
+        lang_color = metadata.language.as_deref().map(language_color).unwrap_or("#ffffff"),
+        release_svg = release_svg,
+        license = escape_xml(metadata.license.as_ref().map(|l| l.name.as_str()).unwrap_or("No License")),
+        updated = escape_xml(date_part(&metadata.updated_at)),
+        attribution = attribution_svg,
+    );
+    (svg, 446.0 + dy + grown)
+}
+
+/// Width of a topic pill: the measured label plus padding
+pub(crate) fn topic_pill_width(topic: &str) -> f32 {
+    (text_width(topic, 12.0, FontWeight::Normal) + 20.0).ceil()
+}
+
+/// Generate SVG for topic badges, as many as fit in `max_width`
+fn generate_topics_svg(topics: &[String], max_topics: usize, max_width: f32) -> String {
+    let mut svg = String::new();
+    let mut x_offset = 0.0;
+    let mut shown = 0;
+    
+    for (index, topic) in topics.iter().take(max_topics).enumerate() {
+        let width = topic_pill_width(topic);
+        let remaining = topics.len() - index - 1;
+        let more_width = if remaining > 0 { text_width(&format!("+{} more", remaining + 1), 12.0, FontWeight::Normal) + 10.0 } else { 0.0 };
+        if x_offset + width + more_width > max_width {
+            break;
+        }
+        svg.push_str(&format!(
+            r##"<g transform="translate({}, 0)"><rect width="{}" height="28" rx="14" fill="#30363d"/><text x="{}" y="19" text-anchor="middle" font-size="12" fill="#8b949e" font-family="{CARD_FONT_FAMILY}">{}</text></g>"##,
+            x_offset,
+            width,
+            width / 2.0,
+            escape_xml(topic)
+        ));
+        x_offset += width + 10.0;
+        shown += 1;
+    }
+    
+    if topics.len() > shown {
+        svg.push_str(&format!(
+            r##"<g transform="translate({}, 0)"><text y="19" font-size="12" fill="#6e7681" font-family="{CARD_FONT_FAMILY}">+{} more</text></g>"##,
+            x_offset,
+            topics.len() - shown
+        ));
+    }
+    
+    svg
+}
+
+/// Generate SVG for a row of contributor avatars. Each avatar sits on a circle
+/// with the contributor's initial, which shows wherever the image cannot load.
+fn generate_contributors_svg(contributors: &[ContributorInfo], max_avatars: usize, ring_color: &str) -> String {
+    let mut svg = String::new();
+    let mut x_offset = 0;
+    
+    for (index, contributor) in contributors.iter().take(max_avatars).enumerate() {
+        let initial = initial(&contributor.login);
+        let image = if contributor.avatar_url.is_empty() {
+            String::new()
+        } else {
+            format!(
+                r##"<clipPath id="contributor-clip-{index}"><circle cx="18" cy="18" r="18"/></clipPath><image href="{url}" width="36" height="36" clip-path="url(#contributor-clip-{index})"/>"##,
+                index = index,
+                url = escape_xml(&contributor.avatar_url)
+            )
+        };
+        svg.push_str(&format!(
+            r##"<g transform="translate({}, 0)"><title>{}</title><circle cx="18" cy="18" r="20" fill="{}"/><circle cx="18" cy="18" r="18" fill="#30363d"/><text x="18" y="24" text-anchor="middle" font-size="16" fill="#8b949e" font-family="{CARD_FONT_FAMILY}">{}</text>{}</g>"##,
+            x_offset,
+            escape_xml(&contributor.login),
+            ring_color,
+            escape_xml(&initial),
+            image
+        ));
+        x_offset += 30;
+    }
+    
+    if contributors.len() > max_avatars {
+        svg.push_str(&format!(
+            r##"<g transform="translate({}, 0)"><text x="16" y="24" font-size="14" fill="#8b949e" font-family="{CARD_FONT_FAMILY}">+{} contributors</text></g>"##,
+            x_offset + 6,
+            contributors.len() - max_avatars
+        ));
+    }
+    
+    svg
+}
+
+/// Generate SVG for a GitHub-style stacked language bar with a legend beneath.
+/// Languages past the first few, and any that would overflow the legend, are
+/// folded into "Other".
+fn generate_languages_svg(languages: &[LanguageStat], width: u32, text_color: &str) -> String {
+    const MAX_SEGMENTS: usize = 6;
+    const LEGEND_ROWS: u32 = 2;
+    
+    let total: f64 = languages.iter().map(|l| l.percentage).sum();
+    if total <= 0.0 {
+        return String::new();
+    }
+    
+    // Lay the legend out first so the bar shows exactly the languages it names
+    let mut shown: Vec<(&str, &str, f64, u32, u32)> = Vec::new();
+    let (mut x_offset, mut row) = (0, 0);
+    for language in languages.iter().take(MAX_SEGMENTS) {
+        let label = format!("{} {:.1}%", language.name, language.percentage / total * 100.0);
+        let label_width = (text_width(&label, 13.0, FontWeight::Normal) + 40.0).ceil() as u32;
+        if x_offset > 0 && x_offset + label_width > width {
+            x_offset = 0;
+            row += 1;
+        }
+        if row >= LEGEND_ROWS {
+            break;
+        }
+        shown.push((&language.name, language_color(&language.name), language.percentage, x_offset, row));
+        x_offset += label_width;
+    }
+    let other = total - shown.iter().map(|s| s.2).sum::<f64>();
+    
+    let mut bar = String::new();
+    let mut legend = String::new();
+    let mut bar_offset = 0.0;
+    let segments = shown
+        .iter()
+        .map(|&(name, color, percentage, _, _)| (name, color, percentage))
+        .chain((other >= 0.05).then_some(("Other", DEFAULT_LANGUAGE_COLOR, other)));
+    for (name, color, percentage) in segments {
+        let segment_width = percentage / total * width as f64;
+        bar.push_str(&format!(
+            r##"<rect x="{:.1}" width="{:.1}" height="8" fill="{}"><title>{}</title></rect>"##,
+            bar_offset,
+            segment_width,
+            color,
+            escape_xml(name)
+        ));
+        bar_offset += segment_width;
+    }
+    
+    for &(name, color, percentage, x, row) in &shown {
+        legend.push_str(&format!(
+            r##"<g transform="translate({}, {})"><circle cx="5" cy="5" r="5" fill="{}"/><text x="16" y="10" font-size="13" fill="{}" font-family="{CARD_FONT_FAMILY}">{} <tspan opacity="0.7">{:.1}%</tspan></text></g>"##,
+            x,
+            24 + row * 22,
+            color,
+            text_color,
+            escape_xml(name),
+            percentage / total * 100.0
+        ));
+    }
+    
+    format!(
+        r##"<clipPath id="languages-clip"><rect width="{width}" height="8" rx="4"/></clipPath><g clip-path="url(#languages-clip)">{bar}</g>{legend}"##,
+        width = width,
+        bar = bar,
+        legend = legend
+    )
+}
+
+/// Rasterize SVG to PNG using resvg
+pub fn rasterize_svg(svg_content: &str, width: u32) -> Result<Vec<u8>, AppError> {
+    render_pixmap(svg_content, width)?
+        .encode_png()
+        .map_err(|e| AppError::render(format!("Failed to encode PNG: {}", e)))
+}
+
+/// Draw the SVG `width` pixels wide, the height following its aspect ratio
+pub(crate) fn render_pixmap(svg_content: &str, width: u32) -> Result<tiny_skia::Pixmap, AppError> {
+    let tree = usvg::Tree::from_str(svg_content, &render_options())
+        .map_err(|e| AppError::render(format!("Failed to parse SVG: {}", e)))?;
+    
+    let size = tree.size();
+    let scale = width as f32 / size.width();
+    let height = (size.height() * scale) as u32;
+    
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| AppError::render("Failed to create pixmap"))?;
+    
+    let transform = tiny_skia::Transform::from_scale(scale, scale);
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    Ok(pixmap)
+}
+
+/// Generate README snippet markdown
+pub fn generate_readme_snippet(metadata: &RepoMetadata, include_attribution: bool) -> String {
+    let description = metadata.description.as_deref().unwrap_or("A GitHub repository");
+    let license = metadata.license.as_ref()
+        .map(|l| format!("![License](https://img.shields.io/badge/license-{}-blue.svg)", l.spdx_id.as_deref().unwrap_or(&l.key)))
+        .unwrap_or_default();
+    
+    let attribution = if include_attribution {
+        format!("\n\n---\n\n<sub>{}</sub>", ATTRIBUTION_TEXT)
+    } else {
+        String::new()
+    };
+    
+    // KZ signature Easter egg
+    let kz_signature = "<!-- KZ signature: LAZYFROG -->\n";
+    
+    format!(r#"{kz_sig}# {name}
+
+{description}
+
+[![Stars](https://img.shields.io/github/stars/{full_name}?style=social)](https://github.com/{full_name})
+[![Forks](https://img.shields.io/github/forks/{full_name}?style=social)](https://github.com/{full_name}/fork)
+{license}
+
+## 📊 Stats
+
+| Metric | Count |
+|--------|-------|
+| ⭐ Stars | {stars} |
+| 🍴 Forks | {forks} |
+| 🔓 Issues | {issues} |
+
+## 🔗 Links
+
+- **Repository**: [{full_name}]({url})
+- **Language**: {language}
+- **License**: {license_name}
+
+## 🚀 Quick Start
+
+```bash
+git clone {url}.git
+cd {name}
+```
+{attribution}"#,
+        kz_sig = kz_signature,
+        name = metadata.name,
+        description = description,
+        full_name = metadata.full_name,
+        stars = format_count(metadata.stargazers_count),
+        forks = format_count(metadata.forks_count),
+        issues = format_count(metadata.open_issues_count),
+        url = metadata.html_url,
+        language = metadata.language.as_deref().unwrap_or("Not specified"),
+        license = license,
+        license_name = metadata.license.as_ref().map(|l| l.name.as_str()).unwrap_or("Not specified"),
+        attribution = attribution,
+    )
+}
+
+/// Sections release notes use when a project configures none, by conventional commit prefix
+pub fn default_release_note_categories() -> Vec<ReleaseNoteCategory> {
+    let category = |title: &str, prefixes: &[&str]| ReleaseNoteCategory {
+        title: title.to_string(),
+        prefixes: prefixes.iter().map(|p| p.to_string()).collect(),
+    };
+    vec![
+        category("✨ Features", &["feat", "feature"]),
+        category("🐛 Bug Fixes", &["fix", "bug"]),
+        category("📚 Documentation", &["doc"]),
+        category("🔧 Maintenance", &["chore", "ci", "build"]),
+    ]
+}
+
+/// Generate release notes draft from commits, sorted into `categories`
+/// (the built-in ones when empty)
+pub fn generate_release_notes_draft(
+    metadata: &RepoMetadata,
+    commits: &[CommitInfo],
+    version: Option<String>,
+    range: Option<&CommitRange>,
+    categories: &[ReleaseNoteCategory],
+    include_attribution: bool,
+) -> String {
+    // A range ending at a tag names the release it describes
+    let version = version
+        .or_else(|| range.and_then(|r| r.head.clone()))
+        .unwrap_or_else(|| "v0.0.0".to_string());
+    let date = Utc::now().format("%Y-%m-%d").to_string();
+    
+    let defaults;
+    let categories = if categories.is_empty() {
+        defaults = default_release_note_categories();
+        &defaults
+    } else {
+        categories
+    };
+    
+    // Each commit goes to the first category with a matching prefix
+    let mut sections: Vec<Vec<&CommitInfo>> = vec![Vec::new(); categories.len()];
+    let mut other: Vec<&CommitInfo> = Vec::new();
+    
+    for commit in commits {
+        let msg = commit.message.to_lowercase();
+        let category = categories
+            .iter()
+            .position(|c| c.prefixes.iter().any(|p| msg.starts_with(&p.to_lowercase())));
+        match category {
+            Some(index) => sections[index].push(commit),
+            None => other.push(commit),
+        }
+    }
+    
+    let mut notes = format!(r#"# {name} {version}
+
+**Release Date**: {date}
+
+## What's Changed
+
+"#, name = metadata.name, version = version, date = date);
+    
+    for (category, section) in categories.iter().zip(&sections) {
+        push_commit_section(&mut notes, &category.title, section);
+    }
+    push_commit_section(&mut notes, "📝 Other Changes", &other);
+    
+    let newcomers = new_contributors(metadata, commits);
+    if !newcomers.is_empty() {
+        notes.push_str("### 🎉 New Contributors\n\n");
+        for commit in newcomers {
+            let who = match &commit.author_login {
+                Some(login) => format!("@{}", login),
+                None => commit.author_name.clone(),
+            };
+            notes.push_str(&format!("- {} made their first contribution in `{}`\n", who, commit.sha));
+        }
+        notes.push('\n');
+    }
+    
+    notes.push_str(&format!(r#"## 📦 Installation
+
+```bash
+git clone {url}.git
+cd {name}
+git checkout {version}
+```
+
+## 🔗 Links
+
+- **Full Changelog**: {changelog}
+- **Repository**: {url}
+"#,
+        url = metadata.html_url,
+        name = metadata.name,
+        version = version,
+        changelog = changelog_url(metadata, range),
+    ));
+    
+    if include_attribution {
+        notes.push_str(&format!("\n---\n\n<sub>{}</sub>\n", ATTRIBUTION_TEXT));
+    }
+    
+    notes
+}
+
+/// A `###` list of commits; nothing when the list is empty
+fn push_commit_section(notes: &mut String, title: &str, commits: &[&CommitInfo]) {
+    if commits.is_empty() {
+        return;
+    }
+    
+    notes.push_str(&format!("### {}\n\n", title));
+    for commit in commits {
+        notes.push_str(&format!("- {} (`{}`)\n", commit.message, commit.sha));
+    }
+    notes.push('\n');
+}
+
+/// Compare view for a `base..head` range, otherwise the commit history of the branch
+fn changelog_url(metadata: &RepoMetadata, range: Option<&CommitRange>) -> String {
+    let head = range
+        .and_then(|r| r.head.as_deref())
+        .unwrap_or(&metadata.default_branch);
+    
+    match range.and_then(|r| r.base.as_deref()) {
+        // GitLab keeps repository pages under `/-/`
+        Some(base) if metadata.html_url.contains("gitlab") => {
+            format!("{}/-/compare/{}...{}", metadata.html_url, base, head)
+        }
+        Some(base) => format!("{}/compare/{}...{}", metadata.html_url, base, head),
+        None => format!("{}/commits/{}", metadata.html_url, head),
+    }
+}
+
+/// Contributors section for the press kit; empty when none are known
+fn press_kit_contributors(contributors: &[ContributorInfo]) -> String {
+    if contributors.is_empty() {
+        return String::new();
+    }
+    
+    let mut section = String::from("\n## Contributors\n\n");
+    for contributor in contributors.iter().take(10) {
+        let name = if contributor.html_url.is_empty() {
+            contributor.login.clone()
+        } else {
+            format!("[{}]({})", contributor.login, contributor.html_url)
+        };
+        let plural = if contributor.contributions == 1 { "" } else { "s" };
+        section.push_str(&format!("- {} — {} contribution{}\n", name, contributor.contributions, plural));
+    }
+    if contributors.len() > 10 {
+        section.push_str(&format!("- …and {} more\n", contributors.len() - 10));
+    }
+    section
+}
+
+/// Commit authors whose every known contribution is in `commits`, in order of first appearance
+fn new_contributors<'a>(metadata: &RepoMetadata, commits: &'a [CommitInfo]) -> Vec<&'a CommitInfo> {
+    let mut firsts: Vec<&CommitInfo> = Vec::new();
+    // Oldest first, so each author is credited with their first commit
+    for commit in commits.iter().rev() {
+        let key = commit.contributor_key();
+        if firsts.iter().any(|c| c.contributor_key() == key) {
+            continue;
+        }
+        let in_range = commits.iter().filter(|c| c.contributor_key() == key).count() as u32;
+        let is_new = metadata
+            .contributors
+            .iter()
+            .any(|c| c.login == key && c.contributions <= in_range);
+        if is_new {
+            firsts.push(commit);
+        }
+    }
+    firsts
+}
+
+/// Generate press kit overview markdown
+pub fn generate_press_kit(metadata: &RepoMetadata, options: &ExportOptions) -> String {
+    let description = metadata.description.as_deref().unwrap_or("A software project");
+    
+    let attribution = if options.include_attribution {
+        format!("\n---\n\n<sub>{}</sub>", ATTRIBUTION_TEXT)
+    } else {
+        String::new()
+    };
+    
+    format!(r#"# {name} — Press Kit
+
+## Overview
+
+**{name}** is {description}
+
+## Quick Facts
+
+| | |
+|---|---|
+| **Name** | {name} |
+| **Author** | [{owner}]({owner_url}) |
+| **Repository** | [{full_name}]({url}) |
+| **Language** | {language} |
+| **License** | {license} |
+| **Stars** | {stars} |
+| **Forks** | {forks} |
+
+## Description
+
+{description}
+
+## Key Features
+
+- Primary language: **{language}**
+- Active development with **{issues}** open issues
+- Last updated: **{updated}**
+
+## Topics / Tags
+
+{topics}
+{contributors}
+## Assets
+
+The following assets are included in this press kit:
+
+{assets}
+## Screenshots
+
+Place screenshots in the `{screenshots}/` folder.
+
+## Contact
+
+- **Repository**: {url}
+- **Owner**: {owner_url}
+
+## License
+
+This project is licensed under **{license}**.
+{attribution}"#,
+        name = metadata.name,
+        description = description,
+        owner = metadata.owner.login,
+        owner_url = metadata.owner.html_url,
+        full_name = metadata.full_name,
+        url = metadata.html_url,
+        language = metadata.language.as_deref().unwrap_or("Not specified"),
+        license = metadata.license.as_ref().map(|l| l.name.as_str()).unwrap_or("Not specified"),
+        stars = format_count(metadata.stargazers_count),
+        forks = format_count(metadata.forks_count),
+        issues = format_count(metadata.open_issues_count),
+        updated = date_part(&metadata.updated_at),
+        topics = if metadata.topics.is_empty() {
+            "No topics specified".to_string()
+        } else {
+            metadata.topics.iter().map(|t| format!("`{}`", t)).collect::<Vec<_>>().join(", ")
+        },
+        contributors = press_kit_contributors(&metadata.contributors),
+        assets = press_kit_assets(options),
+        screenshots = options.layout.screenshots.trim_end_matches('/'),
+        attribution = attribution,
+    )
+}
+
+/// The files `export_full_share_kit` writes with `options`, relative to the share kit folder
+fn press_kit_assets(options: &ExportOptions) -> String {
+    let layout = &options.layout;
+    let format = options.image.format;
+    let card = |name: &str, size: SizePreset| {
+        let (width, height) = size.dimensions();
+        format!(
+            "- `{name}.svg` — Vector social card (editable)\n- `{name}.{ext}` — {format} social card, {label} ({width}×{height})\n",
+            name = name,
+            ext = format.extension(),
+            format = format.extension().to_uppercase(),
+            label = size.label(),
+            width = width,
+            height = height,
+        )
+    };
+    
+    let mut assets = card(&layout.card, options.size);
+    if options.all_sizes {
+        for size in SizePreset::ALL {
+            assets.push_str(&card(&format!("{}-{}", layout.card, size.id()), size));
+        }
+    }
+    assets.push_str(&format!("- `{}` — Ready-to-use README section\n", layout.readme));
+    assets.push_str(&format!("- `{}` — Auto-generated release notes template\n", layout.release_notes));
+    assets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metadata;
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1000), "1.0K");
+        assert_eq!(format_count(1500), "1.5K");
+        assert_eq!(format_count(1000000), "1.0M");
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("<test>"), "&lt;test&gt;");
+        assert_eq!(escape_xml("a & b"), "a &amp; b");
+        assert_eq!(escape_xml("bell\u{7}\ttab"), "bell\ttab");
+    }
+    
+    #[test]
+    fn test_cards_refuse_colours_that_are_not_hex() {
+        for color in ["#abc", "#0D1117", "#0d1117cc"] {
+            assert!(validate_color(color).is_ok(), "{}", color);
+        }
+        for color in ["", "red", "#12", "#ggg", "#0d1117\"><script>alert(1)</script>"] {
+            assert_eq!(validate_color(color).unwrap_err().kind(), "parse", "{}", color);
+        }
+        let error = generate_svg(&sample_metadata(), "gradient", true, false, false, None, Some("#fff\"/>".to_string())).unwrap_err();
+        assert_eq!(error.kind(), "parse");
+    }
+
+    #[test]
+    fn test_truncate_keeps_graphemes_whole() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("abcdefghij", 8), "abcde...");
+        // Full-width characters take two columns each
+        assert_eq!(truncate("漢字かな交じり文", 9), "漢字か...");
+        // A family emoji is one grapheme of several code points
+        assert_eq!(truncate("👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦", 7), "👨‍👩‍👧‍👦👨‍👩‍👧‍👦...");
+        assert_eq!(truncate("e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}", 4), "e\u{301}...");
+        assert_eq!(truncate("long", 2), "...");
+    }
+    
+    #[test]
+    fn test_initial_is_a_whole_grapheme() {
+        assert_eq!(initial("octocat"), "O");
+        assert_eq!(initial("éclair"), "É");
+        assert_eq!(initial("e\u{301}mile"), "E\u{301}");
+        assert_eq!(initial("👩‍💻dev"), "👩‍💻");
+        assert_eq!(initial("  "), "?");
+        assert_eq!(date_part("2024-06-01T00:00:00Z"), "2024-06-01");
+        assert_eq!(date_part("2024年6月"), "2024年6月");
+    }
+
+    #[test]
+    fn test_generate_modern_template() {
+        let metadata = sample_metadata();
+        let svg = generate_svg(&metadata, "modern", true, false, false, None, None).unwrap();
+        assert!(svg.contains("test-repo"));
+        assert!(svg.contains("RepoCard Studio"));
+        // Easter egg verification
+        assert!(svg.contains("<!-- KZ: LAZYFROG :: frogprints -->"));
+        assert!(svg.contains("opacity=\"0.03\"")); // Frogprints
+    }
+
+    #[test]
+    fn test_generate_readme_snippet() {
+        let metadata = sample_metadata();
+        let snippet = generate_readme_snippet(&metadata, true);
+        assert!(snippet.contains("# test-repo"));
+        assert!(snippet.contains("RepoCard Studio"));
+        // Easter egg verification
+        assert!(snippet.contains("<!-- KZ signature: LAZYFROG -->"));
+    }
+
+    // ============================================
+    // SNAPSHOT/HASH TESTS FOR DETERMINISTIC OUTPUT
+    // ============================================
+    // These tests ensure template output is consistent and
+    // any visual changes require intentional snapshot updates.
+
+    /// Snapshot test for Modern template
+    /// If this test fails, the template output has changed.
+    /// Update the expected hash only for intentional visual changes.
+    #[test]
+    fn test_modern_template_snapshot() {
+        let metadata = sample_metadata();
+        let svg = generate_svg(&metadata, "modern", true, false, false, Some("#0d1117".to_string()), Some("#161b22".to_string())).unwrap();
+        
+        // Verify structure is deterministic
+        assert!(svg.starts_with("<!-- KZ: LAZYFROG :: frogprints -->"));
+        assert!(svg.contains("width=\"1200\" height=\"630\""));
+        assert!(svg.contains("viewBox=\"0 0 1200 630\""));
+        
+        // Key sections must be present
+        assert!(svg.contains("<!-- Background -->"));
+        assert!(svg.contains("<!-- Header -->"));
+        assert!(svg.contains("<!-- Description -->"));
+        assert!(svg.contains("<!-- Stats -->"));
+        assert!(svg.contains("<!-- Footer -->"));
+        assert!(svg.contains("<!-- Frogprints Easter Egg -->"));
+        
+        // Metadata binding verification
+        assert!(svg.contains("owner"));
+        assert!(svg.contains("test-repo"));
+        assert!(svg.contains("1.2K")); // stars
+        assert!(svg.contains("56")); // forks
+        assert!(svg.contains("Rust"));
+    }
+
+    /// Snapshot test for Minimal template
+    #[test]
+    fn test_minimal_template_snapshot() {
+        let metadata = sample_metadata();
+        let svg = generate_svg(&metadata, "minimal", true, false, false, None, None).unwrap();
+        
+        // Verify structure is deterministic
+        assert!(svg.starts_with("<!-- KZ: LAZYFROG :: frogprints -->"));
+        assert!(svg.contains("width=\"1200\" height=\"630\""));
+        
+        // Minimal uses white background by default
+        assert!(svg.contains("fill=\"#ffffff\""));
+        
+        // Key sections
+        assert!(svg.contains("<!-- Background -->"));
+        assert!(svg.contains("<!-- Frogprints Easter Egg -->"));
+        assert!(svg.contains("opacity=\"0.025\"")); // Minimal uses 2.5% opacity
+        
+        // Stats row format
+        assert!(svg.contains("stars"));
+        assert!(svg.contains("forks"));
+        assert!(svg.contains("issues"));
+    }
+
+    /// Snapshot test for Gradient template
+    #[test]
+    fn test_gradient_template_snapshot() {
+        let metadata = sample_metadata();
+        let svg = generate_svg(&metadata, "gradient", true, false, false, Some("#667eea".to_string()), Some("#764ba2".to_string())).unwrap();
+        
+        // Verify structure is deterministic
+        assert!(svg.starts_with("<!-- KZ: LAZYFROG :: frogprints -->"));
+        assert!(svg.contains("width=\"1200\" height=\"630\""));
+        
+        // Gradient-specific elements
+        assert!(svg.contains("linearGradient"));
+        assert!(svg.contains("id=\"bg-gradient\""));
+        assert!(svg.contains("#667eea"));
+        assert!(svg.contains("#764ba2"));
+        
+        // Decorative circles
+        assert!(svg.contains("rgba(255,255,255,0.05)"));
+        
+        // Glass pill stats
+        assert!(svg.contains("rgba(255,255,255,0.2)"));
+        
+        // Frogprints at 4% opacity
+        assert!(svg.contains("opacity=\"0.04\""));
+    }
+
+    /// Test README snippet determinism
+    #[test]
+    fn test_readme_snippet_snapshot() {
+        let metadata = sample_metadata();
+        let snippet = generate_readme_snippet(&metadata, true);
+        
+        // Must start with KZ signature
+        assert!(snippet.starts_with("<!-- KZ signature: LAZYFROG -->"));
+        
+        // Required sections
+        assert!(snippet.contains("## 📊 Stats"));
+        assert!(snippet.contains("## 🔗 Links"));
+        assert!(snippet.contains("## 🚀 Quick Start"));
+        
+        // Shields.io badges
+        assert!(snippet.contains("img.shields.io/github/stars"));
+        assert!(snippet.contains("img.shields.io/github/forks"));
+        
+        // Attribution at end
+        assert!(snippet.contains("kindware.dev"));
+    }
+
+    /// Test that all templates maintain canvas dimensions
+    #[test]
+    fn test_all_templates_canvas_dimensions() {
+        let metadata = sample_metadata();
+        
+        for template_id in &["modern", "minimal", "gradient"] {
+            let svg = generate_svg(&metadata, template_id, true, false, false, None, None).unwrap();
+            assert!(svg.contains("width=\"1200\""), "Template {} missing width", template_id);
+            assert!(svg.contains("height=\"630\""), "Template {} missing height", template_id);
+            assert!(svg.contains("viewBox=\"0 0 1200 630\""), "Template {} missing viewBox", template_id);
+        }
+        
+        let error = generate_svg(&metadata, "retro", true, false, false, None, None).unwrap_err();
+        assert_eq!(error, AppError::template("Unknown template: retro"));
+    }
+
+    #[test]
+    fn test_templates_fill_every_size_preset() {
+        let metadata = sample_metadata();
+        for template_id in ["modern", "minimal", "gradient"] {
+            for size in crate::SizePreset::ALL {
+                let options = ExportOptions {
+                    template_id: template_id.to_string(),
+                    show_contributors: true,
+                    show_languages: true,
+                    size,
+                    ..ExportOptions::default()
+                };
+                let svg = generate_card_svg(&metadata, &options).unwrap();
+                let (width, height) = size.dimensions();
+                assert!(svg.contains(&format!("<svg width=\"{}\" height=\"{}\"", width, height)), "{} {:?}", template_id, size);
+
+                let pixmap = render_pixmap(&svg, width).unwrap();
+                assert_eq!((pixmap.width(), pixmap.height()), (width, height), "{} {:?}", template_id, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_user_templates_are_centred_on_other_sizes() {
+        let svg = r#"<?xml version="1.0"?><svg width="1200" height="630" xmlns="http://www.w3.org/2000/svg"><rect width="1200" height="630"/></svg>"#;
+        assert_eq!(fit_to_canvas(svg, 1200, 630, &Canvas::new(crate::SizePreset::OpenGraph)), svg);
+
+        let square = fit_to_canvas(svg, 1200, 630, &Canvas::new(crate::SizePreset::Square));
+        assert!(square.starts_with(r#"<svg width="1080" height="1080" viewBox="0 0 1080 1080""#));
+        assert!(square.contains(r#"<g transform="translate(0, 256.5) scale(0.9)"><svg width="1200""#));
+        assert!(!square.contains("<?xml"));
+        assert_eq!(render_pixmap(&square, 1080).unwrap().height(), 1080);
+    }
+
+    #[test]
+    fn test_tall_cards_stack_the_rows() {
+        let row_y = |svg: &str, row: &str| -> (f32, f32) {
+            let translate = svg.split(row).nth(1).unwrap().split("translate(").nth(1).unwrap().split(')').next().unwrap();
+            let (x, y) = translate.split_once(", ").unwrap();
+            (x.parse().unwrap(), y.parse().unwrap())
+        };
+        let options = ExportOptions { show_contributors: true, show_languages: true, ..ExportOptions::default() };
+        let wide = generate_card_svg(&sample_metadata(), &options).unwrap();
+        assert_eq!(row_y(&wide, "<!-- Languages -->"), (720.0, 430.0));
+
+        let story = ExportOptions { size: crate::SizePreset::Story, ..options };
+        let tall = generate_card_svg(&sample_metadata(), &story).unwrap();
+        let (contributors_x, contributors_y) = row_y(&tall, "<!-- Contributors -->");
+        assert_eq!(row_y(&tall, "<!-- Languages -->"), (contributors_x, contributors_y + STACKED_ROW_GAP));
+        assert!(tall.contains("viewBox=\"0 0 1200 2133.3\""));
+    }
+
+    #[test]
+    fn test_release_notes_for_tag_range() {
+        let range = CommitRange::parse("v1.2.0..v1.3.0").unwrap();
+        let notes = generate_release_notes_draft(&sample_metadata(), &[], None, Some(&range), &[], false);
+        assert!(notes.starts_with("# test-repo v1.3.0"));
+        assert!(notes.contains("https://github.com/owner/test-repo/compare/v1.2.0...v1.3.0"));
+        
+        let notes = generate_release_notes_draft(&sample_metadata(), &[], None, None, &[], false);
+        assert!(notes.contains("https://github.com/owner/test-repo/commits/main"));
+    }
+
+    #[test]
+    fn test_templates_show_latest_release() {
+        let metadata = sample_metadata();
+        for template_id in ["modern", "minimal", "gradient"] {
+            let svg = generate_svg(&metadata, template_id, false, false, false, None, None).unwrap();
+            assert!(svg.contains("v1.4.0"), "Template {} missing latest release", template_id);
+        }
+        
+        let metadata = RepoMetadata { latest_release: None, ..sample_metadata() };
+        let svg = generate_svg(&metadata, "modern", false, false, false, None, None).unwrap();
+        assert!(!svg.contains("Latest Release"));
+    }
+
+    fn commit(sha: &str, login: &str) -> CommitInfo {
+        CommitInfo {
+            sha: sha.to_string(),
+            message: "feat: something".to_string(),
+            author_name: login.to_uppercase(),
+            author_email: format!("{}@example.com", login),
+            date: "2024-05-01T00:00:00Z".to_string(),
+            author_login: Some(login.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_release_notes_credit_new_contributors() {
+        let commits = vec![commit("ccc3333", "owner"), commit("bbb2222", "newcomer"), commit("aaa1111", "owner")];
+        let notes = generate_release_notes_draft(&sample_metadata(), &commits, None, None, &[], false);
+        assert!(notes.contains("### 🎉 New Contributors"));
+        assert!(notes.contains("- @newcomer made their first contribution in `bbb2222`"));
+        assert!(!notes.contains("@owner made"));
+    }
+
+    #[test]
+    fn test_release_notes_use_configured_categories() {
+        let mut commits = vec![commit("aaa1111", "owner"), commit("bbb2222", "owner")];
+        commits[1].message = "Perf: faster parsing".to_string();
+        
+        let notes = generate_release_notes_draft(&sample_metadata(), &commits, None, None, &[], false);
+        assert!(notes.contains("### ✨ Features\n\n- feat: something (`aaa1111`)"));
+        assert!(notes.contains("### 📝 Other Changes\n\n- Perf: faster parsing (`bbb2222`)"));
+        
+        let categories = vec![ReleaseNoteCategory {
+            title: "⚡ Performance".to_string(),
+            prefixes: vec!["perf".to_string()],
+        }];
+        let notes = generate_release_notes_draft(&sample_metadata(), &commits, None, None, &categories, false);
+        assert!(notes.contains("### ⚡ Performance\n\n- Perf: faster parsing (`bbb2222`)"));
+        assert!(notes.contains("### 📝 Other Changes\n\n- feat: something (`aaa1111`)"));
+        assert!(!notes.contains("### ✨ Features"));
+    }
+
+    #[test]
+    fn test_press_kit_lists_contributors() {
+        let options = ExportOptions { include_attribution: false, ..ExportOptions::default() };
+        let kit = generate_press_kit(&sample_metadata(), &options);
+        assert!(kit.contains("## Contributors"));
+        assert!(kit.contains("- [owner](https://github.com/owner) — 120 contributions"));
+        assert!(kit.contains("- [newcomer](https://github.com/newcomer) — 1 contribution\n"));
+        
+        let metadata = RepoMetadata { contributors: Vec::new(), ..sample_metadata() };
+        assert!(!generate_press_kit(&metadata, &options).contains("## Contributors"));
+    }
+
+    #[test]
+    fn test_press_kit_lists_the_exported_files() {
+        let kit = generate_press_kit(&sample_metadata(), &ExportOptions::default());
+        assert!(kit.contains("- `repo-card.svg` — Vector social card (editable)\n- `repo-card.png` — PNG social card, Open Graph (1200×630)\n"));
+        assert!(kit.contains("- `README-snippet.md` — Ready-to-use README section\n"));
+        assert!(kit.contains("Place screenshots in the `press-kit/screenshots/` folder."));
+        
+        let mut options = ExportOptions { size: SizePreset::Story, all_sizes: true, ..ExportOptions::default() };
+        options.layout.card = "card".to_string();
+        options.layout.readme = "docs/snippet.md".to_string();
+        options.image.format = crate::ImageFormat::Webp;
+        let kit = generate_press_kit(&sample_metadata(), &options);
+        assert!(kit.contains("- `card.webp` — WEBP social card, Story (1080×1920)\n"));
+        assert!(kit.contains("- `card-twitter.webp` — WEBP social card, X / Twitter (1600×900)\n"));
+        assert_eq!(kit.matches(".svg`").count(), 1 + SizePreset::ALL.len());
+        assert!(kit.contains("- `docs/snippet.md` —"));
+        assert!(!kit.contains("repo-card"));
+    }
+
+    #[test]
+    fn test_contributor_row_is_optional() {
+        let metadata = sample_metadata();
+        for template_id in ["modern", "minimal", "gradient"] {
+            let svg = generate_svg(&metadata, template_id, false, true, false, None, None).unwrap();
+            assert!(svg.contains("<!-- Contributors -->"), "Template {} missing contributors", template_id);
+            assert!(svg.contains("contributor-clip-0"));
+            assert!(svg.trim_end().ends_with("</svg>"));
+            assert!(rasterize_svg(&svg, 600).is_ok());
+            
+            let svg = generate_svg(&metadata, template_id, false, false, false, None, None).unwrap();
+            assert!(!svg.contains("<!-- Contributors -->"));
+        }
+    }
+
+    #[test]
+    fn test_language_bar_is_optional() {
+        let metadata = sample_metadata();
+        for template_id in ["modern", "minimal", "gradient"] {
+            let svg = generate_svg(&metadata, template_id, false, false, true, None, None).unwrap();
+            assert!(svg.contains("<!-- Languages -->"), "Template {} missing languages", template_id);
+            assert!(svg.contains("#dea584") && svg.contains("70.0%"));
+            assert!(svg.trim_end().ends_with("</svg>"));
+            assert!(rasterize_svg(&svg, 600).is_ok());
+            
+            let svg = generate_svg(&metadata, template_id, false, false, false, None, None).unwrap();
+            assert!(!svg.contains("<!-- Languages -->"));
+        }
+    }
+
+    #[test]
+    fn test_language_bar_folds_overflow_into_other() {
+        let languages = crate::language_breakdown((0..10).map(|i| (format!("Language{}", i), 100 - i)));
+        let svg = generate_languages_svg(&languages, 400, "#8b949e");
+        
+        assert!(svg.contains("<title>Other</title>"));
+        assert_eq!(svg.matches("<circle").count(), svg.matches("<rect x=").count() - 1);
+        assert!(!generate_languages_svg(&[], 400, "#8b949e").contains("<rect"));
+    }
+
+    #[test]
+    fn test_long_text_stays_inside_the_card() {
+        let mut metadata = sample_metadata();
+        metadata.full_name = "an-organisation-with-a-long-name/and-an-even-longer-repository-name".to_string();
+        metadata.name = "and-an-even-longer-repository-name".to_string();
+        metadata.description = Some("Ｗｉｄｅ　ｇｌｙｐｈｓ and a description long enough to need wrapping over more than one line, then some more words so it has to shrink and finally be cut short with an ellipsis at the end ".repeat(3));
+        metadata.topics = (0..5).map(|i| format!("a-rather-long-topic-name-that-takes-room-{}", i)).collect();
+        metadata.language = Some("Jupyter Notebook".to_string());
+        
+        for (template_id, width) in [("modern", 1040.0), ("minimal", 1000.0), ("gradient", 920.0)] {
+            let svg = generate_svg(&metadata, template_id, true, false, false, None, None).unwrap();
+            let description = svg.split("<!-- Description -->").nth(1).unwrap().split("</text>").next().unwrap();
+            let size: f32 = description.split("font-size=\"").nth(1).unwrap().split('"').next().unwrap().parse().unwrap();
+            let lines: Vec<&str> = description.split("\">").skip(2).map(|l| l.split("</tspan>").next().unwrap()).collect();
+            assert_eq!(lines.len(), 2, "{}: {:?}", template_id, lines);
+            assert!(lines[1].ends_with('…'));
+            for line in lines {
+                assert!(text_width(line, size, FontWeight::Normal) <= width, "{}: {:?} overflows", template_id, line);
+            }
+            assert!(rasterize_svg(&svg, 300).is_ok());
+        }
+        
+        let topics = generate_topics_svg(&metadata.topics, 5, 1040.0);
+        let shown = topics.matches("<rect").count();
+        assert!(shown < 5 && topics.contains(&format!("+{} more", 5 - shown)));
+    }
+    
+    #[test]
+    fn test_modern_template_embeds_owner_avatar() {
+        let mut metadata = sample_metadata();
+        let svg = generate_svg(&metadata, "modern", false, false, false, None, None).unwrap();
+        assert!(!svg.contains("<!-- Owner Avatar -->"));
+        
+        let png = rasterize_svg(r##"<svg width="4" height="4" xmlns="http://www.w3.org/2000/svg"><rect width="4" height="4" fill="#ff0000"/></svg>"##, 4).unwrap();
+        metadata.owner.avatar_data_uri = crate::image_data_uri(&png);
+        let svg = generate_svg(&metadata, "modern", false, false, false, None, None).unwrap();
+        assert!(svg.contains("<!-- Owner Avatar -->"));
+        assert!(svg.contains("href=\"data:image/png;base64,"));
+        assert!(svg.contains(">O</text>"), "initial stays underneath as the fallback");
+        
+        // The avatar is drawn over the placeholder circle in the PNG too
+        let rendered = rasterize_svg(&svg, 1200).unwrap();
+        let pixmap = tiny_skia::Pixmap::decode_png(&rendered).unwrap();
+        let pixel = pixmap.pixel(120, 120).unwrap();
+        assert!(pixel.red() > 200 && pixel.green() < 50, "avatar missing from PNG: {:?}", pixel);
+    }
+    
+    mod unicode_props {
+        use super::*;
+        use crate::{user_templates::template_context, LanguageStat, UserTemplate};
+        use proptest::prelude::*;
+        
+        /// Text that stresses byte offsets: multibyte scripts, emoji sequences, combining marks, controls
+        fn text() -> impl Strategy<Value = String> {
+            let tricky = prop::sample::select(vec![
+                "漢字", "👨‍👩‍👧‍👦", "🏳️‍🌈", "e\u{301}", "\u{202e}rtl", "עברית", "العربية", "\u{0}", "\u{7}", "&<>\"'", "\u{fffe}", " ", "\n",
+            ]);
+            prop_oneof![
+                "\\PC{0,120}",
+                any::<String>(),
+                prop::collection::vec(tricky, 0..40).prop_map(|parts| parts.concat()),
+            ]
+        }
+        
+        fn metadata() -> impl Strategy<Value = RepoMetadata> {
+            (
+                (text(), text(), prop::option::of(text()), text(), prop::option::of(text())),
+                (prop::collection::vec(text(), 0..8), prop::option::of(text()), text(), text()),
+                (prop::collection::vec(text(), 0..10), prop::collection::vec((text(), 0.0..100.0f64), 0..8)),
+                any::<u32>(),
+            )
+                .prop_map(|((name, owner, description, full_name, language), (topics, release, license, updated), (logins, languages), stars)| {
+                    let mut metadata = sample_metadata();
+                    metadata.name = name;
+                    metadata.owner.login = owner;
+                    metadata.description = description;
+                    metadata.full_name = full_name;
+                    metadata.language = language;
+                    metadata.topics = topics;
+                    metadata.latest_release = release;
+                    metadata.license.as_mut().unwrap().name = license;
+                    metadata.updated_at = updated;
+                    metadata.stargazers_count = stars;
+                    metadata.contributors = logins
+                        .into_iter()
+                        .map(|login| ContributorInfo { login, avatar_url: String::new(), html_url: String::new(), contributions: 1 })
+                        .collect();
+                    metadata.languages = languages
+                        .into_iter()
+                        .map(|(name, percentage)| LanguageStat { name, bytes: 0, percentage })
+                        .collect();
+                    metadata
+                })
+        }
+        
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(48))]
+            
+            #[test]
+            fn prop_every_template_renders_valid_svg(metadata in metadata(), attribution: bool) {
+                for template_id in ["modern", "minimal", "gradient"] {
+                    let svg = generate_svg(&metadata, template_id, attribution, true, true, None, None).unwrap();
+                    let parsed = usvg::Tree::from_str(&svg, &usvg::Options::default());
+                    prop_assert!(parsed.is_ok(), "{} produced invalid SVG: {:?}", template_id, parsed.err());
+                }
+                
+                let source = r#"<svg width="100" height="100" xmlns="http://www.w3.org/2000/svg"><text>{{owner_initial}} {{repo}} {{description}}{{#each topics}}{{name}}{{/each}}{{#each contributors}}{{initial}}{{/each}}{{#each languages}}{{name}}{{/each}}</text></svg>"#;
+                let context = template_context(&metadata, attribution, true, true, None, None);
+                let svg = UserTemplate::parse("props", source).unwrap().render(&context);
+                prop_assert!(usvg::Tree::from_str(&svg, &usvg::Options::default()).is_ok());
+            }
+            
+            #[test]
+            fn prop_text_generators_never_panic(metadata in metadata(), messages in prop::collection::vec(text(), 0..6), version in prop::option::of(text())) {
+                let commits: Vec<CommitInfo> = messages
+                    .into_iter()
+                    .map(|message| CommitInfo {
+                        sha: "0123456789abcdef".to_string(),
+                        message,
+                        author_name: metadata.owner.login.clone(),
+                        author_email: String::new(),
+                        date: metadata.updated_at.clone(),
+                        author_login: None,
+                    })
+                    .collect();
+                generate_readme_snippet(&metadata, true);
+                generate_press_kit(&metadata, &ExportOptions::default());
+                generate_release_notes_draft(&metadata, &commits, version, None, &default_release_note_categories(), true);
+            }
+            
+            #[test]
+            fn prop_truncate_fits_and_keeps_a_prefix(input in text(), max_width in 0usize..40) {
+                let truncated = truncate(&input, max_width);
+                match truncated.strip_suffix("...") {
+                    Some(kept) if truncated != input => {
+                        prop_assert!(input.starts_with(kept));
+                        prop_assert!(kept.width() + 3 <= max_width.max(3));
+                        prop_assert!(input[kept.len()..].graphemes(true).next().is_some());
+                        prop_assert!(input.grapheme_indices(true).any(|(i, _)| i == kept.len()));
+                    }
+                    _ => prop_assert_eq!(&truncated, &input),
+                }
+                prop_assert!(!initial(&input).is_empty());
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// RepoCard Studio - Test Support Module
+// LAZYFROG (KZ) — kindware.dev
+
+//! Fixtures shared by the tests of this crate and, with the `test-support` feature, its dependents.
+
+use crate::{language_breakdown, ContributorInfo, LicenseInfo, OwnerInfo, RepoMetadata};
+
+/// A repository with two contributors, four languages and a release
+pub fn sample_metadata() -> RepoMetadata {
+    RepoMetadata {
+        name: "test-repo".to_string(),
+        full_name: "owner/test-repo".to_string(),
+        description: Some("A test repository".to_string()),
+        html_url: "https://github.com/owner/test-repo".to_string(),
+        stargazers_count: 1234,
+        forks_count: 56,
+        watchers_count: 100,
+        open_issues_count: 10,
+        language: Some("Rust".to_string()),
+        topics: vec!["testing".to_string(), "rust".to_string()],
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        updated_at: "2024-06-01T00:00:00Z".to_string(),
+        pushed_at: "2024-06-01T00:00:00Z".to_string(),
+        default_branch: "main".to_string(),
+        license: Some(LicenseInfo {
+            key: "mit".to_string(),
+            name: "MIT License".to_string(),
+            spdx_id: Some("MIT".to_string()),
+        }),
+        owner: OwnerInfo {
+            login: "owner".to_string(),
+            avatar_url: "https://github.com/owner.png".to_string(),
+            avatar_data_uri: None,
+            html_url: "https://github.com/owner".to_string(),
+        },
+        latest_release: Some("v1.4.0".to_string()),
+        contributors: vec![
+            ContributorInfo {
+                login: "owner".to_string(),
+                avatar_url: "https://avatars.githubusercontent.com/u/1".to_string(),
+                html_url: "https://github.com/owner".to_string(),
+                contributions: 120,
+            },
+            ContributorInfo {
+                login: "newcomer".to_string(),
+                avatar_url: String::new(),
+                html_url: "https://github.com/newcomer".to_string(),
+                contributions: 1,
+            },
+        ],
+        languages: language_breakdown(vec![
+            ("Rust".to_string(), 7_000),
+            ("TypeScript".to_string(), 2_000),
+            ("CSS".to_string(), 900),
+            ("Shell".to_string(), 100),
+        ]),
+        project_config: None,
+    }
+}
@@ -0,0 +1,552 @@
+// RepoCard Studio - User Templates Module
+// LAZYFROG (KZ) — kindware.dev
+
+//! Card templates written as SVG files with `{{placeholders}}`, discovered in a
+//! templates directory next to the built-in ones.
+//!
+//! ```svg
+//! <svg width="1200" height="630" xmlns="http://www.w3.org/2000/svg">
+//!   <rect width="1200" height="630" fill="{{primary_color}}"/>
+//!   <text x="80" y="120" fill="#fff">{{owner}} / {{repo}} ★ {{stars}}</text>
+//!   {{#if description}}<text x="80" y="180" fill="#ccc">{{description}}</text>{{/if}}
+//!   {{#each topics}}<text x="{{x}}" y="240" fill="#999">{{name}}</text>{{/each}}
+//! </svg>
+//! ```
+//!
+//! `{{#if}}`/`{{#unless}}` take an `{{else}}`; inside `{{#each}}` the item's fields,
+//! `{{this}}`, `{{@index}}`, `{{@first}}` and `{{@last}}` are in scope. Every value
+//! is XML-escaped.
+
+use crate::error::AppError;
+use crate::languages::{language_color, DEFAULT_LANGUAGE_COLOR};
+use crate::layout::{CARD_FONT_FAMILY, MONO_FONT_FAMILY};
+use crate::templates::{date_part, escape_xml, format_count, initial, topic_pill_width, truncate, ATTRIBUTION_TEXT};
+use crate::{ContributorInfo, LanguageStat, LicenseInfo, OwnerInfo, RepoMetadata};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATES_DIR_NAME: &str = "repocard-studio";
+/// Overrides where user templates are looked up
+pub const TEMPLATES_DIR_ENV: &str = "REPOCARD_TEMPLATES_DIR";
+pub(crate) const TEMPLATE_EXTENSION: &str = "svg";
+/// Colours a user template gets when none are picked
+pub const DEFAULT_PRIMARY_COLOR: &str = "#0d1117";
+pub const DEFAULT_SECONDARY_COLOR: &str = "#161b22";
+
+/// Where user templates live: `REPOCARD_TEMPLATES_DIR`, else `<config dir>/repocard-studio/templates`
+pub fn user_templates_dir() -> Option<PathBuf> {
+    match std::env::var_os(TEMPLATES_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => dirs::config_dir().map(|dir| dir.join(TEMPLATES_DIR_NAME).join("templates")),
+    }
+}
+
+/// A parsed and validated user template
+#[derive(Debug, Clone)]
+pub struct UserTemplate {
+    pub id: String,
+    /// The SVG's `<title>`, if it has a fixed one
+    pub title: Option<String>,
+    /// Canvas size in SVG units
+    pub width: u32,
+    pub height: u32,
+    /// Context values the template uses, sorted
+    pub fields: Vec<String>,
+    nodes: Vec<Node>,
+}
+
+impl UserTemplate {
+    /// Load `<dir>/<id>.svg`
+    pub fn find(dir: &Path, id: &str) -> Result<Self, AppError> {
+        let is_file_name = !id.is_empty() && !id.starts_with('.') && !id.contains(['/', '\\']);
+        let path = dir.join(format!("{}.{}", id, TEMPLATE_EXTENSION));
+        if !is_file_name || !path.is_file() {
+            return Err(AppError::template(format!("Unknown template: {}", id)));
+        }
+        Self::load(&path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let source = fs::read_to_string(path)
+            .map_err(|e| AppError::io(format!("Failed to read template {}: {}", path.display(), e)))?;
+        let id = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+        Self::parse(&id, &source)
+    }
+
+    /// Parse `source` and check that it renders a valid SVG for a sample repository
+    pub fn parse(id: &str, source: &str) -> Result<Self, AppError> {
+        let fail = |message: String| AppError::template(format!("Template {}: {}", id, message));
+        let nodes = parse_nodes(source).map_err(fail)?;
+        if !source.contains("<svg") {
+            return Err(fail("no <svg> element".to_string()));
+        }
+        
+        let context = template_context(&sample_metadata(), true, true, true, None, None);
+        let mut fields = BTreeSet::new();
+        collect_fields(&nodes, &mut vec![&context], &mut fields).map_err(fail)?;
+        let mut template = Self {
+            id: id.to_string(),
+            title: fixed_title(source),
+            width: 0,
+            height: 0,
+            fields: fields.into_iter().collect(),
+            nodes,
+        };
+        let svg = template.render(&context);
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default())
+            .map_err(|e| fail(format!("not a valid SVG: {}", e)))?;
+        template.width = tree.size().width().round() as u32;
+        template.height = tree.size().height().round() as u32;
+        Ok(template)
+    }
+
+    /// Whether the template draws with `primary_color`/`secondary_color`
+    pub fn uses_field(&self, field: &str) -> bool {
+        self.fields.iter().any(|f| f == field)
+    }
+
+    /// Fill the template from a context built by `template_context`
+    pub fn render(&self, context: &Value) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, &mut vec![Scope::root(context)], &mut out);
+        out
+    }
+}
+
+/// Values a template can use. Lists are empty unless their row is switched on, and
+/// carry ready-made offsets since SVG has no arithmetic.
+pub fn template_context(
+    metadata: &RepoMetadata,
+    include_attribution: bool,
+    show_contributors: bool,
+    show_languages: bool,
+    primary_color: Option<String>,
+    secondary_color: Option<String>,
+) -> Value {
+    let mut topic_x = 0;
+    let topics: Vec<Value> = metadata
+        .topics
+        .iter()
+        .map(|topic| {
+            let width = topic_pill_width(topic) as u32;
+            let item = json!({ "name": topic, "x": topic_x, "width": width });
+            topic_x += width + 10;
+            item
+        })
+        .collect();
+    
+    let contributors: Vec<Value> = metadata
+        .contributors
+        .iter()
+        .filter(|_| show_contributors)
+        .enumerate()
+        .map(|(index, c)| {
+            json!({
+                "login": c.login,
+                "initial": initial(&c.login),
+                "avatar_url": c.avatar_url,
+                "contributions": c.contributions,
+                "x": index * 30,
+            })
+        })
+        .collect();
+    
+    // Percentages of the whole, so a nested `<svg viewBox="0 0 100 …" preserveAspectRatio="none">` draws the bar
+    let total: f64 = metadata.languages.iter().map(|l| l.percentage).sum();
+    let mut offset = 0.0;
+    let languages: Vec<Value> = metadata
+        .languages
+        .iter()
+        .filter(|_| show_languages && total > 0.0)
+        .map(|l| {
+            let share = l.percentage / total * 100.0;
+            let item = json!({
+                "name": l.name,
+                "color": language_color(&l.name),
+                "percentage": format!("{:.1}", share),
+                "offset": format!("{:.2}", offset),
+                "width": format!("{:.2}", share),
+            });
+            offset += share;
+            item
+        })
+        .collect();
+    
+    json!({
+        "owner": metadata.owner.login,
+        "owner_initial": initial(&metadata.owner.login),
+        "owner_avatar": metadata.owner.avatar_data_uri.as_deref().filter(|uri| uri.starts_with("data:image/")).unwrap_or_default(),
+        "repo": metadata.name,
+        "full_name": metadata.full_name,
+        "description": metadata.description.as_deref().map(|d| truncate(d, 100)).unwrap_or_default(),
+        "url": metadata.html_url,
+        "stars": format_count(metadata.stargazers_count),
+        "forks": format_count(metadata.forks_count),
+        "watchers": format_count(metadata.watchers_count),
+        "open_issues": format_count(metadata.open_issues_count),
+        "language": metadata.language.as_deref().unwrap_or_default(),
+        "language_color": metadata.language.as_deref().map(language_color).unwrap_or(DEFAULT_LANGUAGE_COLOR),
+        "license": metadata.license.as_ref().map(|l| l.spdx_id.clone().unwrap_or_else(|| l.name.clone())).unwrap_or_default(),
+        "latest_release": metadata.latest_release.as_deref().unwrap_or_default(),
+        "default_branch": metadata.default_branch,
+        "updated_at": date_part(&metadata.updated_at),
+        "topics": topics,
+        "contributors": contributors,
+        "languages": languages,
+        "primary_color": primary_color.unwrap_or_else(|| DEFAULT_PRIMARY_COLOR.to_string()),
+        "secondary_color": secondary_color.unwrap_or_else(|| DEFAULT_SECONDARY_COLOR.to_string()),
+        "font_family": CARD_FONT_FAMILY,
+        "mono_font_family": MONO_FONT_FAMILY,
+        "attribution": if include_attribution { ATTRIBUTION_TEXT } else { "" },
+    })
+}
+
+/// The first `<title>` when it is plain text, as the template's display name
+fn fixed_title(source: &str) -> Option<String> {
+    let start = source.find("<title>")? + "<title>".len();
+    let title = source[start..].split("</title>").next()?.trim();
+    (!title.is_empty() && !title.contains(['{', '<'])).then(|| title.to_string())
+}
+
+/// A repository with every field filled, which templates are validated against
+fn sample_metadata() -> RepoMetadata {
+    RepoMetadata {
+        name: "sample-repo".to_string(),
+        full_name: "octocat/sample-repo".to_string(),
+        description: Some("A sample repository for template previews".to_string()),
+        html_url: "https://github.com/octocat/sample-repo".to_string(),
+        stargazers_count: 12_400,
+        forks_count: 830,
+        watchers_count: 12_400,
+        open_issues_count: 42,
+        language: Some("Rust".to_string()),
+        topics: vec!["cli".to_string(), "svg".to_string(), "open-source".to_string()],
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        updated_at: "2024-06-01T00:00:00Z".to_string(),
+        pushed_at: "2024-06-01T00:00:00Z".to_string(),
+        default_branch: "main".to_string(),
+        license: Some(LicenseInfo {
+            key: "mit".to_string(),
+            name: "MIT License".to_string(),
+            spdx_id: Some("MIT".to_string()),
+        }),
+        owner: OwnerInfo {
+            login: "octocat".to_string(),
+            avatar_url: String::new(),
+            html_url: "https://github.com/octocat".to_string(),
+            avatar_data_uri: None,
+        },
+        latest_release: Some("v2.1.0".to_string()),
+        contributors: vec![ContributorInfo {
+            login: "octocat".to_string(),
+            avatar_url: String::new(),
+            html_url: "https://github.com/octocat".to_string(),
+            contributions: 100,
+        }],
+        languages: vec![
+            LanguageStat { name: "Rust".to_string(), bytes: 0, percentage: 80.0 },
+            LanguageStat { name: "TypeScript".to_string(), bytes: 0, percentage: 20.0 },
+        ],
+        project_config: None,
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Value { path: String, line: usize },
+    If { path: String, negate: bool, line: usize, then: Vec<Node>, otherwise: Vec<Node> },
+    Each { path: String, line: usize, body: Vec<Node> },
+}
+
+/// An open `{{#if}}`, `{{#unless}}` or `{{#each}}` while parsing
+struct Block {
+    helper: &'static str,
+    path: String,
+    line: usize,
+    nodes: Vec<Node>,
+    otherwise: Option<Vec<Node>>,
+}
+
+impl Block {
+    fn current(&mut self) -> &mut Vec<Node> {
+        self.otherwise.as_mut().unwrap_or(&mut self.nodes)
+    }
+}
+
+fn parse_nodes(source: &str) -> Result<Vec<Node>, String> {
+    let mut stack = vec![Block { helper: "", path: String::new(), line: 1, nodes: Vec::new(), otherwise: None }];
+    let mut rest = source;
+    let mut line = 1;
+    
+    while let Some(start) = rest.find("{{") {
+        let (text, tag) = rest.split_at(start);
+        line += text.matches('\n').count();
+        if !text.is_empty() {
+            stack.last_mut().unwrap().current().push(Node::Text(text.to_string()));
+        }
+        let end = tag.find("}}").ok_or_else(|| format!("unclosed {{{{ on line {}", line))?;
+        let inner = tag[2..end].trim();
+        rest = &tag[end + 2..];
+        
+        if inner.starts_with('!') {
+            line += inner.matches('\n').count();
+            continue;
+        }
+        let (word, path) = inner.split_once(char::is_whitespace).map(|(w, p)| (w, p.trim())).unwrap_or((inner, ""));
+        match word {
+            "" => return Err(format!("empty {{{{}}}} on line {}", line)),
+            "#if" | "#unless" | "#each" => {
+                if path.is_empty() {
+                    return Err(format!("{{{{{}}}}} needs a value on line {}", word, line));
+                }
+                let helper = match word {
+                    "#if" => "if",
+                    "#unless" => "unless",
+                    _ => "each",
+                };
+                stack.push(Block { helper, path: path.to_string(), line, nodes: Vec::new(), otherwise: None });
+            }
+            "else" => match stack.last_mut() {
+                Some(block) if matches!(block.helper, "if" | "unless") && block.otherwise.is_none() => {
+                    block.otherwise = Some(Vec::new());
+                }
+                _ => return Err(format!("{{{{else}}}} outside {{{{#if}}}} on line {}", line)),
+            },
+            closing if closing.starts_with('/') => {
+                let block = stack.pop().filter(|_| !stack.is_empty());
+                let block = match block {
+                    Some(block) if block.helper == &closing[1..] => block,
+                    Some(block) => {
+                        return Err(format!(
+                            "{{{{{}}}}} on line {} closes {{{{#{}}}}} from line {}",
+                            closing, line, block.helper, block.line
+                        ))
+                    }
+                    None => return Err(format!("{{{{{}}}}} without an opening block on line {}", closing, line)),
+                };
+                let node = match block.helper {
+                    "each" => Node::Each { path: block.path, line: block.line, body: block.nodes },
+                    helper => Node::If {
+                        path: block.path,
+                        negate: helper == "unless",
+                        line: block.line,
+                        then: block.nodes,
+                        otherwise: block.otherwise.unwrap_or_default(),
+                    },
+                };
+                stack.last_mut().unwrap().current().push(node);
+            }
+            helper if helper.starts_with('#') => return Err(format!("unknown helper {{{{{}}}}} on line {}", helper, line)),
+            _ if !path.is_empty() => return Err(format!("unexpected {{{{{}}}}} on line {}", inner, line)),
+            _ => stack.last_mut().unwrap().current().push(Node::Value { path: word.to_string(), line }),
+        }
+    }
+    if !rest.is_empty() {
+        stack.last_mut().unwrap().current().push(Node::Text(rest.to_string()));
+    }
+    
+    let root = stack.remove(0);
+    match stack.pop() {
+        Some(block) => Err(format!("{{{{#{} {}}}}} on line {} is never closed", block.helper, block.path, block.line)),
+        None => Ok(root.nodes),
+    }
+}
+
+/// One level of `{{#each}}`, or the whole context
+struct Scope<'a> {
+    value: &'a Value,
+    index: usize,
+    len: usize,
+}
+
+impl<'a> Scope<'a> {
+    fn root(value: &'a Value) -> Self {
+        Self { value, index: 0, len: 1 }
+    }
+}
+
+fn lookup<'a>(scopes: &[Scope<'a>], path: &str) -> Option<Cow<'a, Value>> {
+    let scope = scopes.last()?;
+    match path {
+        "this" => return Some(Cow::Borrowed(scope.value)),
+        "@index" => return Some(Cow::Owned(json!(scope.index))),
+        "@first" => return Some(Cow::Owned(json!(scope.index == 0))),
+        "@last" => return Some(Cow::Owned(json!(scope.index + 1 == scope.len))),
+        _ => {}
+    }
+    scopes
+        .iter()
+        .rev()
+        .find_map(|scope| path.split('.').try_fold(scope.value, |value, key| value.get(key)))
+        .map(Cow::Borrowed)
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+fn render_nodes<'a>(nodes: &'a [Node], scopes: &mut Vec<Scope<'a>>, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Value { path, .. } => match lookup(scopes, path).as_deref() {
+                Some(Value::String(s)) => out.push_str(&escape_xml(s)),
+                Some(value @ (Value::Number(_) | Value::Bool(_))) => out.push_str(&value.to_string()),
+                _ => {}
+            },
+            Node::If { path, negate, then, otherwise, .. } => {
+                let truthy = lookup(scopes, path).is_some_and(|value| is_truthy(&value));
+                render_nodes(if truthy != *negate { then } else { otherwise }, scopes, out);
+            }
+            Node::Each { path, body, .. } => {
+                let Some(Cow::Borrowed(Value::Array(items))) = lookup(scopes, path) else { continue };
+                for (index, item) in items.iter().enumerate() {
+                    scopes.push(Scope { value: item, index, len: items.len() });
+                    render_nodes(body, scopes, out);
+                    scopes.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Every placeholder must name a context value, so typos fail on load instead of rendering
+/// blank. Collects the top-level values used into `fields`.
+fn collect_fields(nodes: &[Node], scopes: &mut Vec<&Value>, fields: &mut BTreeSet<String>) -> Result<(), String> {
+    for node in nodes {
+        let (path, line, describe) = match node {
+            Node::Text(_) => continue,
+            Node::Value { path, line } => (path, line, format!("{{{{{}}}}}", path)),
+            Node::If { path, line, .. } => (path, line, format!("{{{{#if {}}}}}", path)),
+            Node::Each { path, line, .. } => (path, line, format!("{{{{#each {}}}}}", path)),
+        };
+        let resolved = if path.starts_with('@') || path == "this" {
+            None
+        } else {
+            let found = scopes.iter().enumerate().rev().find_map(|(depth, scope)| {
+                path.split('.').try_fold(*scope, |v, key| v.get(key)).map(|value| (depth, value))
+            });
+            let Some((depth, value)) = found else {
+                return Err(format!("unknown placeholder {} on line {}", describe, line));
+            };
+            if depth == 0 {
+                fields.insert(path.split('.').next().unwrap_or(path).to_string());
+            }
+            Some(value)
+        };
+
+        match node {
+            Node::If { then, otherwise, .. } => {
+                collect_fields(then, scopes, fields)?;
+                collect_fields(otherwise, scopes, fields)?;
+            }
+            Node::Each { body, .. } => {
+                let Some(Value::Array(items)) = resolved else {
+                    return Err(format!("{} on line {} is not a list", describe, line));
+                };
+                // The sample repository fills every list, so the first item shows the fields
+                if let Some(item) = items.first() {
+                    scopes.push(item);
+                    collect_fields(body, scopes, fields)?;
+                    scopes.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const CARD: &str = r##"<svg width="1200" height="630" xmlns="http://www.w3.org/2000/svg">
+  <rect width="1200" height="630" fill="{{primary_color}}"/>
+  <text x="80" y="120">{{owner}} / {{repo}} ★ {{stars}}</text>
+  {{#if latest_release}}<text x="80" y="160">{{latest_release}}</text>{{else}}<text x="80" y="160">unreleased</text>{{/if}}
+  {{#each topics}}<text x="{{x}}" y="240">{{@index}}:{{name}}{{#unless @last}},{{/unless}}</text>{{/each}}
+  {{! languages only when switched on }}
+  {{#each languages}}<rect x="{{offset}}" width="{{width}}" height="8" fill="{{color}}"/>{{/each}}
+</svg>"##;
+
+    fn context(metadata: &RepoMetadata, show_languages: bool) -> Value {
+        template_context(metadata, true, false, show_languages, Some("#123456".to_string()), None)
+    }
+
+    #[test]
+    fn test_renders_placeholders_loops_and_conditionals() {
+        let template = UserTemplate::parse("card", CARD).unwrap();
+        let mut metadata = sample_metadata();
+        metadata.owner.login = "a&b".to_string();
+        let svg = template.render(&context(&metadata, false));
+
+        assert!(svg.contains(r##"fill="#123456""##));
+        assert!(svg.contains("a&amp;b / sample-repo ★ 12.4K"));
+        assert!(svg.contains(">v2.1.0<"));
+        let second_x = 10 + topic_pill_width("cli") as u32;
+        assert!(svg.contains(&format!(r#"<text x="0" y="240">0:cli,</text><text x="{}" y="240">1:svg,</text>"#, second_x)));
+        assert!(svg.contains(">2:open-source</text>"));
+        assert!(!svg.contains("languages only"));
+        assert!(!svg.contains("<rect x="));
+
+        metadata.latest_release = None;
+        let svg = template.render(&context(&metadata, true));
+        assert!(svg.contains(">unreleased<"));
+        assert!(svg.contains(r##"<rect x="0.00" width="80.00" height="8" fill="#dea584"/>"##));
+        usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_broken_templates_on_load() {
+        let cases = [
+            ("<svg>{{#if description}}</svg>", "{{#if description}} on line 1 is never closed"),
+            ("<svg>\n{{#each topics}}{{/if}}</svg>", "{{/if}} on line 2 closes {{#each}} from line 2"),
+            ("<svg>{{else}}</svg>", "{{else}} outside {{#if}} on line 1"),
+            ("<svg>\n\n{{stars_count}}</svg>", "unknown placeholder {{stars_count}} on line 3"),
+            ("<svg>{{#each topics}}{{title}}{{/each}}</svg>", "unknown placeholder {{title}}"),
+            ("<svg>{{#each owner}}{{/each}}</svg>", "{{#each owner}} on line 1 is not a list"),
+            ("<svg>{{#with owner}}{{/with}}</svg>", "unknown helper {{#with}}"),
+            ("<svg>{{owner</svg>", "unclosed {{ on line 1"),
+            ("<text>{{owner}}</text>", "no <svg> element"),
+            ("<svg><text>{{owner}}</svg>", "not a valid SVG"),
+        ];
+        for (source, expected) in cases {
+            let error = UserTemplate::parse("broken", source).unwrap_err();
+            assert_eq!(error.kind(), "template");
+            assert!(error.message().starts_with("Template broken: "), "{}", error);
+            assert!(error.message().contains(expected), "{} does not mention {}", error, expected);
+        }
+    }
+
+    #[test]
+    fn test_finds_templates_by_file_name() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("zebra.svg"), CARD.replace("<rect", "<title>Zebra Stripes</title>\n  <rect")).unwrap();
+
+        let template = UserTemplate::find(dir.path(), "zebra").unwrap();
+        assert_eq!(template.title.as_deref(), Some("Zebra Stripes"));
+        assert_eq!((template.width, template.height), (1200, 630));
+        assert_eq!(template.fields, ["languages", "latest_release", "owner", "primary_color", "repo", "stars", "topics"]);
+        assert!(!template.uses_field("secondary_color"));
+
+        let svg = template.render(&context(&sample_metadata(), false));
+        assert!(svg.contains("octocat / sample-repo"));
+        for id in ["retro", "../zebra", ".hidden", ""] {
+            let error = UserTemplate::find(dir.path(), id).unwrap_err();
+            assert_eq!(error, AppError::template(format!("Unknown template: {}", id)));
+        }
+    }
+}
@@ -1,111 +1,236 @@
 // RepoCard Studio - Rust Backend
 // LAZYFROG (KZ) — kindware.dev
 
-use serde::{Deserialize, Serialize};
-
-mod github;
-mod export;
-mod templates;
-
-pub use github::*;
-pub use export::*;
-pub use templates::*;
-
-/// Repository metadata from GitHub API
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RepoMetadata {
-    pub name: String,
-    pub full_name: String,
-    pub description: Option<String>,
-    pub html_url: String,
-    pub stargazers_count: u32,
-    pub forks_count: u32,
-    pub watchers_count: u32,
-    pub open_issues_count: u32,
-    pub language: Option<String>,
-    pub topics: Vec<String>,
-    pub created_at: String,
-    pub updated_at: String,
-    pub pushed_at: String,
-    pub default_branch: String,
-    pub license: Option<LicenseInfo>,
-    pub owner: OwnerInfo,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LicenseInfo {
-    pub key: String,
-    pub name: String,
-    pub spdx_id: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OwnerInfo {
-    pub login: String,
-    pub avatar_url: String,
-    pub html_url: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CommitInfo {
-    pub sha: String,
-    pub message: String,
-    pub author_name: String,
-    pub author_email: String,
-    pub date: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExportOptions {
-    pub output_dir: String,
-    pub include_attribution: bool,
-    pub template_id: String,
-    pub primary_color: Option<String>,
-    pub secondary_color: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExportResult {
-    pub success: bool,
-    pub output_path: String,
-    pub files: Vec<String>,
-    pub error: Option<String>,
-}
-
-/// Fetch repository metadata from GitHub
-#[tauri::command]
-async fn fetch_repo(repo_url: String) -> Result<RepoMetadata, String> {
-    github::fetch_repository_metadata(&repo_url).await
+use repocard_core::{
+    export_full_share_kit, generate_card_svg, generate_press_kit, generate_readme_snippet, generate_release_notes_draft,
+    list_templates, rasterize_svg, rasterize_svg_as, render_template_previews, user_templates_dir, AccessToken, AppError,
+    CommitInfo, CommitRange, ContributorInfo, ExportOptions, ExportResult, ProviderKind, RasterOptions, RateLimitStatus,
+    ReleaseInfo, RepoMetadata, RepoProvider, RepoRef, ServerConfig, SizePreset, TagInfo, TemplateInfo, TemplatePreview,
+    TokenInfo, PREVIEW_WIDTH,
+};
+use std::sync::RwLock;
+use tauri::State;
+
+/// Shared backend state managed by Tauri
+struct AppState {
+    /// Long-lived provider clients, replaced when the server settings change
+    providers: RwLock<RepoProvider>,
+}
+
+impl AppState {
+    fn providers(&self) -> RepoProvider {
+        self.providers.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Providers for one command; `refresh` bypasses fresh cache entries
+    fn providers_for(&self, refresh: Option<bool>) -> RepoProvider {
+        let providers = self.providers();
+        if refresh.unwrap_or(false) {
+            providers.refreshing()
+        } else {
+            providers
+        }
+    }
+}
+
+/// Fetch repository metadata from GitHub, GitLab, a Gitea/Forgejo instance or a local checkout
+#[tauri::command]
+async fn fetch_repo(
+    state: State<'_, AppState>,
+    repo_url: String,
+    token: Option<String>,
+    refresh: Option<bool>,
+) -> Result<RepoMetadata, AppError> {
+    state.providers_for(refresh).fetch_repository_metadata(&repo_url, token).await
 }
 
 /// Fetch recent commits from a repository
 #[tauri::command]
-async fn fetch_commits(repo_url: String, count: Option<u32>) -> Result<Vec<CommitInfo>, String> {
-    github::fetch_recent_commits(&repo_url, count.unwrap_or(20)).await
+async fn fetch_commits(
+    state: State<'_, AppState>,
+    repo_url: String,
+    count: Option<u32>,
+    token: Option<String>,
+    refresh: Option<bool>,
+) -> Result<Vec<CommitInfo>, AppError> {
+    state.providers_for(refresh).fetch_recent_commits(&repo_url, count.unwrap_or(20), token).await
+}
+
+/// Fetch every commit in a range such as `v1.2.0..v1.3.0`, a branch or a date window
+#[tauri::command]
+async fn fetch_commit_range(
+    state: State<'_, AppState>,
+    repo_url: String,
+    range: CommitRange,
+    token: Option<String>,
+    refresh: Option<bool>,
+) -> Result<Vec<CommitInfo>, AppError> {
+    state.providers_for(refresh).fetch_commits(&repo_url, &range, token).await
+}
+
+/// Fetch published releases with their assets and download counts
+#[tauri::command]
+async fn fetch_releases(
+    state: State<'_, AppState>,
+    repo_url: String,
+    limit: Option<u32>,
+    token: Option<String>,
+) -> Result<Vec<ReleaseInfo>, AppError> {
+    state.providers().fetch_releases(&repo_url, limit.unwrap_or(20), token).await
+}
+
+/// Fetch top contributors with their contribution counts
+#[tauri::command]
+async fn fetch_contributors(
+    state: State<'_, AppState>,
+    repo_url: String,
+    limit: Option<u32>,
+    token: Option<String>,
+) -> Result<Vec<ContributorInfo>, AppError> {
+    state.providers().fetch_contributors(&repo_url, limit.unwrap_or(30), token).await
+}
+
+/// Fetch repository tags
+#[tauri::command]
+async fn fetch_tags(
+    state: State<'_, AppState>,
+    repo_url: String,
+    limit: Option<u32>,
+    token: Option<String>,
+) -> Result<Vec<TagInfo>, AppError> {
+    state.providers().fetch_tags(&repo_url, limit.unwrap_or(100), token).await
+}
+
+/// Suggest the tag release notes for `version` should start from
+#[tauri::command]
+async fn fetch_previous_tag(
+    state: State<'_, AppState>,
+    repo_url: String,
+    version: Option<String>,
+    token: Option<String>,
+) -> Result<Option<String>, AppError> {
+    state.providers().fetch_previous_tag(&repo_url, version.as_deref(), token).await
+}
+
+/// Parse a pasted repository URL or reference so the UI can validate input as it is typed
+#[tauri::command]
+fn parse_repo_ref(input: String) -> Result<RepoRef, AppError> {
+    RepoRef::parse(&input)
+}
+
+/// Validate a GitHub token and report its scopes
+#[tauri::command]
+async fn validate_github_token(
+    state: State<'_, AppState>,
+    token: Option<String>,
+) -> Result<TokenInfo, AppError> {
+    let token = AccessToken::resolve(ProviderKind::GitHub, token)
+        .ok_or_else(|| AppError::unauthorized("No GitHub token provided or configured"))?;
+    state.providers().github.validate_token(&token).await
+}
+
+/// Get the rate limit state from the most recent response of a provider (GitHub by default)
+#[tauri::command]
+fn get_rate_limit_status(
+    state: State<'_, AppState>,
+    provider: Option<ProviderKind>,
+) -> Option<RateLimitStatus> {
+    state.providers().last_rate_limit(provider.unwrap_or(ProviderKind::GitHub))
+}
+
+/// Point a provider at its public instance or a self-hosted server
+/// (GitHub Enterprise Server, self-managed GitLab, Forgejo)
+#[tauri::command]
+fn configure_server(
+    state: State<'_, AppState>,
+    provider: ProviderKind,
+    server_url: Option<String>,
+    proxy: Option<String>,
+) -> Result<(), AppError> {
+    let mut config = match server_url.filter(|url| !url.trim().is_empty()) {
+        Some(url) => ServerConfig::for_server(provider, &url)?,
+        None => ServerConfig::from_env(provider),
+    };
+    config.proxy = proxy.filter(|p| !p.trim().is_empty());
+    
+    state
+        .providers
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .configure(provider, config)
 }
 
-/// Generate SVG card from template
+/// Generate SVG card from template, at the Open Graph size unless `size` says otherwise
 #[tauri::command]
 fn generate_svg_card(
     metadata: RepoMetadata, 
     template_id: String, 
     include_attribution: bool,
+    show_contributors: Option<bool>,
+    show_languages: Option<bool>,
     primary_color: Option<String>,
     secondary_color: Option<String>,
-) -> Result<String, String> {
-    templates::generate_svg(&metadata, &template_id, include_attribution, primary_color, secondary_color)
+    size: Option<SizePreset>,
+) -> Result<String, AppError> {
+    let options = ExportOptions {
+        template_id,
+        include_attribution,
+        show_contributors: show_contributors.unwrap_or(false),
+        show_languages: show_languages.unwrap_or(false),
+        primary_color,
+        secondary_color,
+        size: size.unwrap_or_default(),
+        ..ExportOptions::default()
+    };
+    generate_card_svg(&metadata, &options)
+}
+
+/// Built-in templates and those in the user templates directory, broken ones with their error
+#[tauri::command]
+fn list_card_templates() -> Vec<TemplateInfo> {
+    list_templates(user_templates_dir().as_deref())
+}
+
+/// Thumbnails of every template for one repository, in the order `list_card_templates` lists them.
+/// Rasterizing is blocking work, so it runs off the async runtime's threads.
+#[tauri::command]
+async fn preview_card_templates(
+    metadata: RepoMetadata,
+    include_attribution: bool,
+    show_contributors: Option<bool>,
+    show_languages: Option<bool>,
+    width: Option<u32>,
+) -> Result<Vec<TemplatePreview>, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        render_template_previews(
+            &metadata,
+            user_templates_dir().as_deref(),
+            include_attribution,
+            show_contributors.unwrap_or(false),
+            show_languages.unwrap_or(false),
+            width.unwrap_or(PREVIEW_WIDTH),
+        )
+    })
+    .await
+    .map_err(|e| AppError::render(format!("Preview task failed: {}", e)))
 }
 
 /// Convert SVG to PNG using resvg
 #[tauri::command]
-fn svg_to_png(svg_content: String, width: Option<u32>) -> Result<Vec<u8>, String> {
-    templates::rasterize_svg(&svg_content, width.unwrap_or(1200))
+fn svg_to_png(svg_content: String, width: Option<u32>) -> Result<Vec<u8>, AppError> {
+    rasterize_svg(&svg_content, width.unwrap_or(1200))
+}
+
+/// Convert SVG to PNG, JPEG, WebP or AVIF, optionally within a size limit
+#[tauri::command]
+fn svg_to_image(svg_content: String, width: Option<u32>, options: RasterOptions) -> Result<Vec<u8>, AppError> {
+    rasterize_svg_as(&svg_content, width.unwrap_or(1200), &options)
 }
 
 /// Generate README snippet
 #[tauri::command]
 fn create_readme_snippet(metadata: RepoMetadata, include_attribution: bool) -> String {
-    templates::generate_readme_snippet(&metadata, include_attribution)
+    generate_readme_snippet(&metadata, include_attribution)
 }
 
 /// Generate release notes draft from commits
@@ -114,15 +239,26 @@ fn generate_release_notes(
     metadata: RepoMetadata, 
     commits: Vec<CommitInfo>, 
     version: Option<String>,
+    range: Option<CommitRange>,
     include_attribution: bool,
 ) -> String {
-    templates::generate_release_notes_draft(&metadata, &commits, version, include_attribution)
+    let categories = metadata
+        .project_config
+        .as_ref()
+        .map(|config| config.release_notes.categories.clone())
+        .unwrap_or_default();
+    generate_release_notes_draft(&metadata, &commits, version, range.as_ref(), &categories, include_attribution)
 }
 
 /// Generate press kit overview
 #[tauri::command]
 fn generate_press_kit_overview(metadata: RepoMetadata, include_attribution: bool) -> String {
-    templates::generate_press_kit(&metadata, include_attribution)
+    // Lists the files an export writes, which the repository's `.repocard.toml` may rename
+    let mut options = ExportOptions { include_attribution, ..ExportOptions::default() };
+    if let Some(config) = &metadata.project_config {
+        config.apply_non_ui_options(&mut options);
+    }
+    generate_press_kit(&metadata, &options)
 }
 
 /// Export full share kit to filesystem
@@ -130,31 +266,52 @@ fn generate_press_kit_overview(metadata: RepoMetadata, include_attribution: bool
 async fn export_share_kit(
     metadata: RepoMetadata,
     commits: Vec<CommitInfo>,
-    options: ExportOptions,
-) -> Result<ExportResult, String> {
-    export::export_full_share_kit(&metadata, &commits, &options).await
+    mut options: ExportOptions,
+) -> Result<ExportResult, AppError> {
+    // The app has no controls for these, so the repository's `.repocard.toml` decides
+    if let Some(config) = &metadata.project_config {
+        config.apply_non_ui_options(&mut options);
+    }
+    export_full_share_kit(&metadata, &commits, &options).await
 }
 
 /// Get default export directory
 #[tauri::command]
-fn get_default_export_dir() -> Result<String, String> {
+fn get_default_export_dir() -> Result<String, AppError> {
     dirs::download_dir()
         .or_else(dirs::document_dir)
         .map(|p| p.to_string_lossy().to_string())
-        .ok_or_else(|| "Could not determine default export directory".to_string())
+        .ok_or_else(|| AppError::io("Could not determine default export directory"))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let providers = RepoProvider::from_env().expect("failed to create API clients");
+    
     tauri::Builder::default()
+        .manage(AppState {
+            providers: RwLock::new(providers),
+        })
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .invoke_handler(tauri::generate_handler![
             fetch_repo,
             fetch_commits,
+            fetch_commit_range,
+            fetch_releases,
+            fetch_contributors,
+            fetch_tags,
+            fetch_previous_tag,
+            parse_repo_ref,
+            validate_github_token,
+            get_rate_limit_status,
+            configure_server,
             generate_svg_card,
+            list_card_templates,
+            preview_card_templates,
             svg_to_png,
+            svg_to_image,
             create_readme_snippet,
             generate_release_notes,
             generate_press_kit_overview,
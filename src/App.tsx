@@ -23,7 +23,18 @@ interface RepoMetadata {
   pushed_at: string;
   default_branch: string;
   license: { key: string; name: string; spdx_id: string | null } | null;
-  owner: { login: string; avatar_url: string; html_url: string };
+  owner: { login: string; avatar_url: string; html_url: string; avatar_data_uri?: string | null };
+  latest_release?: string | null;
+  contributors?: { login: string; avatar_url: string; html_url: string; contributions: number }[];
+  languages?: { name: string; bytes: number; percentage: number }[];
+  project_config?: ProjectConfig | null;
+}
+
+/** The repository's `.repocard.toml`; unset keys keep the app defaults */
+interface ProjectConfig {
+  template: string | null;
+  attribution: boolean | null;
+  colors: { primary: string | null; secondary: string | null };
 }
 
 interface CommitInfo {
@@ -41,8 +52,66 @@ interface ExportResult {
   error: string | null;
 }
 
+type ErrorKind =
+  | "not_found"
+  | "rate_limited"
+  | "unauthorized"
+  | "network"
+  | "parse"
+  | "io"
+  | "template"
+  | "render";
+
+interface AppError {
+  kind: ErrorKind;
+  message: string;
+  resets_at?: string | null;
+}
+
+function describeError(err: unknown): string {
+  if (typeof err !== "object" || err === null || !("kind" in err)) {
+    return String(err);
+  }
+  const { kind, message } = err as AppError;
+  switch (kind) {
+    case "not_found":
+      return `Repository not found. ${message}`;
+    case "rate_limited":
+      return `${message}. Add an access token to raise the limit.`;
+    case "unauthorized":
+      return `${message}. Check that your access token is valid and can read this repository.`;
+    case "network":
+      return `Could not reach the server. ${message}`;
+    default:
+      return message;
+  }
+}
+
 type TabId = "card" | "readme" | "release" | "press";
-type TemplateId = "modern" | "minimal" | "gradient";
+/** A built-in template id, or a user template's file name without `.svg` */
+type TemplateId = string;
+
+type ColorSlotName = "primary" | "secondary";
+
+/** A template as described by the backend registry */
+interface TemplateInfo {
+  id: TemplateId;
+  name: string;
+  source: "builtin" | "user";
+  path: string | null;
+  width: number;
+  height: number;
+  colors: { slot: ColorSlotName; default: string }[];
+  fields: string[];
+  /** Why a user template failed to load */
+  error: string | null;
+}
+
+interface TemplatePreview {
+  id: TemplateId;
+  image: string | null;
+  error: string | null;
+}
 
 const ATTRIBUTION_TEXT = "Generated with RepoCard Studio — LAZYFROG (KZ) — kindware.dev";
 
@@ -63,6 +132,8 @@ function App() {
 
   // Options
   const [template, setTemplate] = useState<TemplateId>("modern");
+  const [templates, setTemplates] = useState<TemplateInfo[]>([]);
+  const [thumbnails, setThumbnails] = useState<Record<TemplateId, string>>({});
   const [includeAttribution, setIncludeAttribution] = useState(true);
   const [primaryColor, setPrimaryColor] = useState("#0d1117");
   const [secondaryColor, setSecondaryColor] = useState("#161b22");
@@ -85,6 +156,9 @@ function App() {
       .catch(console.error);
   }, []);
 
+  const templateInfo = templates.find((t) => t.id === template);
+  const hasColor = (slot: ColorSlotName) => templateInfo?.colors.some((c) => c.slot === slot) ?? false;
+
   // Generate previews when metadata/options change
   const generatePreviews = useCallback(async () => {
     if (!metadata) return;
@@ -95,8 +169,8 @@ function App() {
         metadata,
         templateId: template,
         includeAttribution,
-        primaryColor: template !== "minimal" ? primaryColor : null,
-        secondaryColor: template !== "minimal" ? secondaryColor : null,
+        primaryColor: hasColor("primary") ? primaryColor : null,
+        secondaryColor: hasColor("secondary") ? secondaryColor : null,
       });
       setSvgPreview(svg);
 
@@ -125,14 +199,50 @@ function App() {
     } catch (err) {
       console.error("Preview generation error:", err);
     }
-  }, [metadata, commits, template, includeAttribution, primaryColor, secondaryColor]);
+  }, [metadata, commits, template, templateInfo, includeAttribution, primaryColor, secondaryColor]);
 
   useEffect(() => {
     generatePreviews();
   }, [generatePreviews]);
 
-  // Fetch repository data
-  const fetchRepo = async () => {
+  // Built-ins plus whatever is in the user templates directory
+  useEffect(() => {
+    invoke<TemplateInfo[]>("list_card_templates")
+      .then(setTemplates)
+      .catch(console.error);
+  }, []);
+
+  // Thumbnails of every template for the loaded repository
+  useEffect(() => {
+    if (!metadata) return;
+    invoke<TemplatePreview[]>("preview_card_templates", { metadata, includeAttribution })
+      .then((previews) =>
+        setThumbnails(Object.fromEntries(previews.filter((p) => p.image).map((p) => [p.id, p.image as string])))
+      )
+      .catch(console.error);
+  }, [metadata, includeAttribution]);
+
+  // Switch template, starting from its own colours
+  const selectTemplate = (info: TemplateInfo) => {
+    setTemplate(info.id);
+    for (const { slot, default: color } of info.colors) {
+      if (slot === "primary") setPrimaryColor(color);
+      if (slot === "secondary") setSecondaryColor(color);
+    }
+  };
+
+  // Start from the repository's checked-in preferences so teammates export identical assets
+  const applyProjectConfig = (config?: ProjectConfig | null) => {
+    if (!config) return;
+    const configured = templates.find((t) => t.id === config.template && !t.error);
+    if (configured) selectTemplate(configured);
+    if (config.attribution !== null) setIncludeAttribution(config.attribution);
+    if (config.colors.primary) setPrimaryColor(config.colors.primary);
+    if (config.colors.secondary) setSecondaryColor(config.colors.secondary);
+  };
+
+  // Fetch repository data; `refresh` revalidates cached responses
+  const fetchRepo = async (refresh = false) => {
     if (!repoUrl.trim()) {
       setError("Please enter a GitHub repository URL");
       return;
@@ -143,16 +253,18 @@ function App() {
     setSuccess(null);
 
     try {
-      const meta = await invoke<RepoMetadata>("fetch_repo", { repoUrl });
+      const meta = await invoke<RepoMetadata>("fetch_repo", { repoUrl, refresh });
       setMetadata(meta);
+      applyProjectConfig(meta.project_config);
 
       const repoCommits = await invoke<CommitInfo[]>("fetch_commits", {
         repoUrl,
         count: 20,
+        refresh,
       });
       setCommits(repoCommits);
     } catch (err) {
-      setError(err as string);
+      setError(describeError(err));
       setMetadata(null);
       setCommits([]);
     } finally {
@@ -196,8 +308,8 @@ function App() {
           output_dir: exportDir,
           include_attribution: includeAttribution,
           template_id: template,
-          primary_color: primaryColor,
-          secondary_color: secondaryColor,
+          primary_color: hasColor("primary") ? primaryColor : null,
+          secondary_color: hasColor("secondary") ? secondaryColor : null,
         },
       });
 
@@ -207,7 +319,7 @@ function App() {
         setError(result.error || "Export failed");
       }
     } catch (err) {
-      setError(err as string);
+      setError(describeError(err));
     } finally {
       setExporting(false);
     }
@@ -252,11 +364,19 @@ function App() {
                 />
                 <button
                   className="btn btn-primary"
-                  onClick={fetchRepo}
+                  onClick={() => fetchRepo()}
                   disabled={loading}
                 >
                   {loading ? "..." : "Fetch"}
                 </button>
+                <button
+                  className="btn btn-secondary"
+                  onClick={() => fetchRepo(true)}
+                  disabled={loading || !metadata}
+                  title="Refresh from the server, bypassing the cache"
+                >
+                  ↻
+                </button>
               </div>
             </div>
 
@@ -345,53 +465,59 @@ function App() {
             <div className="input-group">
               <label>Template</label>
               <div className="template-grid">
-                {(["modern", "minimal", "gradient"] as TemplateId[]).map(
-                  (t) => (
-                    <button
-                      key={t}
-                      type="button"
-                      className={`template-option ${template === t ? "selected" : ""}`}
-                      onClick={() => setTemplate(t)}
-                      aria-current={template === t ? "true" : undefined}
-                      aria-label={`Select ${t} template`}
-                    >
-                      <div className={`template-preview template-preview-${t}`} />
-                      <div className="template-name">
-                        {t.charAt(0).toUpperCase() + t.slice(1)}
-                      </div>
-                    </button>
-                  )
-                )}
+                {templates.map((t) => (
+                  <button
+                    key={`${t.source}-${t.id}`}
+                    type="button"
+                    className={`template-option ${template === t.id ? "selected" : ""}`}
+                    onClick={() => selectTemplate(t)}
+                    disabled={t.error !== null}
+                    title={t.error ?? t.path ?? undefined}
+                    aria-current={template === t.id ? "true" : undefined}
+                    aria-label={`Select ${t.name} template`}
+                  >
+                    {thumbnails[t.id] ? (
+                      <img className="template-preview" src={thumbnails[t.id]} alt="" />
+                    ) : (
+                      <div className={`template-preview template-preview-${t.source === "user" ? "user" : t.id}`} />
+                    )}
+                    <div className="template-name">{t.name}</div>
+                  </button>
+                ))}
               </div>
             </div>
 
             {/* Color Pickers */}
-            {template !== "minimal" && (
+            {templateInfo && templateInfo.colors.length > 0 && (
               <div className="input-group">
                 <label>Colors</label>
                 <div className="color-picker-row">
-                  <div className="color-picker-group">
-                    <label htmlFor="primary-color">Primary</label>
-                    <input
-                      id="primary-color"
-                      type="color"
-                      className="color-picker"
-                      title="Select primary color"
-                      value={primaryColor}
-                      onChange={(e) => setPrimaryColor(e.target.value)}
-                    />
-                  </div>
-                  <div className="color-picker-group">
-                    <label htmlFor="secondary-color">Secondary</label>
-                    <input
-                      id="secondary-color"
-                      type="color"
-                      className="color-picker"
-                      title="Select secondary color"
-                      value={secondaryColor}
-                      onChange={(e) => setSecondaryColor(e.target.value)}
-                    />
-                  </div>
+                  {hasColor("primary") && (
+                    <div className="color-picker-group">
+                      <label htmlFor="primary-color">Primary</label>
+                      <input
+                        id="primary-color"
+                        type="color"
+                        className="color-picker"
+                        title="Select primary color"
+                        value={primaryColor}
+                        onChange={(e) => setPrimaryColor(e.target.value)}
+                      />
+                    </div>
+                  )}
+                  {hasColor("secondary") && (
+                    <div className="color-picker-group">
+                      <label htmlFor="secondary-color">Secondary</label>
+                      <input
+                        id="secondary-color"
+                        type="color"
+                        className="color-picker"
+                        title="Select secondary color"
+                        value={secondaryColor}
+                        onChange={(e) => setSecondaryColor(e.target.value)}
+                      />
+                    </div>
+                  )}
                 </div>
               </div>
             )}